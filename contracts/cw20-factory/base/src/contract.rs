@@ -1,23 +1,32 @@
 use std::marker::PhantomData;
 
 use cosmwasm_std::{
-    attr, Addr, Binary, Coin, CosmosMsg, CustomQuery, Deps, DepsMut, Empty, Env, Int256,
-    MessageInfo, Response, StdResult, Storage, Uint128, WasmMsg,
+    attr, Addr, Api, BankMsg, Binary, Coin, CosmosMsg, CustomQuery, Deps, DepsMut, Empty, Env,
+    Int256, MessageInfo, Order, Reply, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
-use cw20::{Cw20ExecuteMsg, TokenInfoResponse};
+use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg, LogoInfo, TokenInfoResponse};
 use cw20_base::{
+    allowances::deduct_allowance,
     msg::QueryMsg as Cw20QueryMsg,
-    state::{BALANCES, TOKEN_INFO},
+    state::{BALANCES, MARKETING_INFO, TOKEN_INFO},
     ContractError as Cw20BaseError,
 };
+use cw_storage_plus::Bound;
 
 use cw20_factory_pkg::{
     cw20_factory::{
-        definitions::TransmuteIntoMsg,
-        interface::TokenFactoryInterface,
+        definitions::{
+            ClawbackAdmin, CreateNativeAuthority, NativeAdmin, SupplyCapUpdate, SupplyDrift,
+            TransmuteHook, TransmuteIntoMsg,
+        },
+        interface::{
+            NativeMetadata, TokenFactoryInterface, FORCE_TRANSFER_REPLY_ID,
+            INDEXER_REFRESH_REPLY_ID,
+        },
         msgs::{
-            ExecuteMsg, InitNativeDetails, InstantiateMsg, MigrateMsg, QueryMsg,
-            SupplyDetailsResponse,
+            BlocklistResponse, Cw20HookMsg, ExecuteMsg, HealthResponse, IndexersResponse,
+            InitNativeDetails, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg,
+            SupplyDetailsResponse, SupplyReconciliationResponse,
         },
         traits::IntoCustom,
         ContractResponse, ContractResult, Cw20FactoryError,
@@ -30,7 +39,17 @@ use rhaki_cw_plus::{
     wasm::WasmMsgBuilder,
 };
 
-use crate::state::FactoryDenom;
+use crate::state::{
+    FactoryDenom, PendingForceTransfer, BLOCKLIST, CLAWBACK_ADMIN, CREATE_NATIVE_AUTHORITY,
+    CW20_CAP, FLAGGED_DRIFT, INDEXERS, NATIVE_ADMIN, NATIVE_BURNED, NATIVE_CAP, NATIVE_MINTED,
+    NATIVE_SUPPLY, PAUSED, PENDING_FORCE_TRANSFER,
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+const MAX_INDEXERS: u32 = 10;
+const HEALTH_DEFAULT_LIMIT: u32 = 100;
+const HEALTH_MAX_LIMIT: u32 = 500;
 
 pub struct Cw20FactoryBase<CQ: CustomQuery, I: TokenFactoryInterface<CQ, CM>, CM = Empty> {
     pub custom_query: PhantomData<CQ>,
@@ -58,18 +77,48 @@ where
             msg.clone().into(),
         )?;
 
+        CREATE_NATIVE_AUTHORITY.save(
+            deps.storage,
+            &msg.create_native_authority.unwrap_or_default(),
+        )?;
+
+        if let Some(clawback_admin) = &msg.clawback_admin {
+            CLAWBACK_ADMIN.save(
+                deps.storage,
+                &ClawbackAdmin::Enabled(clawback_admin.into_addr(deps.api)?),
+            )?;
+        }
+
+        Self::save_supply_caps(deps.storage, msg.native_cap, msg.cw20_cap)?;
+        Self::validate_cw20_cap(deps.as_ref().into_empty())?;
+
         let mut response = Response::new()
             .add_attributes(base_response.attributes)
-            .add_submessages(base_response.messages.to_custom());
+            .add_submessages(base_response.messages.to_custom_with(&I::map_msg)?);
 
         if let Some(init_native) = msg.init_native {
-            let interface_response = I::instantiate(deps.branch(), &env, info, msg.symbol)?;
+            let refund_msgs = Self::collect_creation_fee(deps.as_ref(), &env, &info)?;
+
+            let interface_response =
+                I::instantiate(deps.branch(), &env, info, msg.symbol, msg.subdenom)?;
 
-            response = response.add_messages(interface_response.messages);
+            let hook_msgs =
+                I::set_before_send_hook(deps.branch(), &env, &interface_response.factory_denom)?;
+
+            let metadata_msgs =
+                Self::native_metadata_msgs(deps.branch(), &env, &interface_response.factory_denom)?;
+
+            response = response
+                .add_messages(interface_response.messages)
+                .add_messages(hook_msgs)
+                .add_messages(metadata_msgs)
+                .add_messages(refund_msgs);
 
             if let InitNativeDetails::WithIndexer(indexer) = init_native {
+                let indexer = indexer.into_addr(deps.api)?;
+                INDEXERS.save(deps.storage, &indexer, &Empty {})?;
                 let indexer_msg: CosmosMsg<CM> = WasmMsg::build_execute(
-                    indexer.into_addr(deps.api)?,
+                    indexer,
                     cw20_factory_pkg::cw20_indexer::msgs::ExecuteMsg::RegisterDenom(
                         RegisterDenomMsg {
                             denom: interface_response.factory_denom.clone(),
@@ -83,6 +132,7 @@ where
 
             let factory_denom = FactoryDenom::new(interface_response.factory_denom.clone());
             factory_denom.save(deps.storage)?;
+            NATIVE_SUPPLY.save(deps.storage, &Uint128::zero())?;
 
             response = response
                 .add_attributes(interface_response.attributes)
@@ -93,13 +143,45 @@ where
     }
 
     pub fn execute(
+        mut deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> ContractResponse<CM> {
+        let metadata_update = matches!(
+            msg,
+            ExecuteMsg::UpdateMarketing { .. } | ExecuteMsg::UploadLogo(..)
+        );
+        let supplies = Self::current_supplies(deps.storage)?;
+
+        let mut response = Self::dispatch_execute(deps.branch(), env, info, msg)?;
+
+        if metadata_update || Self::current_supplies(deps.storage)? != supplies {
+            response = response.add_submessages(Self::indexer_refresh_msgs(deps.storage)?);
+        }
+
+        Ok(response)
+    }
+
+    fn dispatch_execute(
         deps: DepsMut<CQ>,
         env: Env,
         info: MessageInfo,
         msg: ExecuteMsg,
     ) -> ContractResponse<CM> {
+        let msg = Self::self_send_into_transmute(&env, msg)?;
+
+        if let Some(addresses) = Self::involved_addresses(info.sender.as_str(), &msg) {
+            Self::assert_not_restricted(deps.as_ref().into_empty(), &addresses)?;
+        }
+
         match msg {
             ExecuteMsg::TransmuteInto(into) => Self::run_transmute(deps, env, info, into),
+            ExecuteMsg::TransmuteFrom {
+                owner,
+                amount,
+                recipient,
+            } => Self::run_transmute_from(deps, env, info, owner, amount, recipient),
             ExecuteMsg::Burn { amount } => Self::run_burn(deps, env, info, amount),
             ExecuteMsg::Mint {
                 recipient,
@@ -107,22 +189,63 @@ where
                 as_native,
             } => Self::run_mint(deps, env, info, recipient, amount, as_native),
             ExecuteMsg::RegisterToIndexer { indexer_addr } => {
-                Self::run_register_into_indexer(deps, indexer_addr)
+                Self::run_register_into_indexer(deps, env, info, indexer_addr)
             }
-            ExecuteMsg::CreateNative {} => Self::run_create_native(deps, env, info),
-            _ => {
-                let base: Cw20ExecuteMsg = msg.into_binary()?.des_into()?;
-
-                cw20_base::contract::execute(deps.into_empty(), env.clone(), info.clone(), base)?
-                    .to_custom()
-                    .wrap_ok()
+            ExecuteMsg::RefreshIndexers { start_after, limit } => {
+                Self::run_refresh_indexers(deps, start_after, limit)
+            }
+            ExecuteMsg::Reconcile { flag } => Self::run_reconcile(deps, env, info, flag),
+            ExecuteMsg::UpdateSupplyCaps {
+                native_cap,
+                cw20_cap,
+            } => Self::run_update_supply_caps(deps, env, info, native_cap, cw20_cap),
+            ExecuteMsg::UnregisterFromIndexer { indexer_addr } => {
+                Self::run_unregister_from_indexer(deps, env, info, indexer_addr)
+            }
+            ExecuteMsg::CreateNative { subdenom } => {
+                Self::run_create_native(deps, env, info, subdenom)
+            }
+            ExecuteMsg::UpdateNativeAdmin { new_admin } => {
+                Self::run_update_native_admin(deps, env, info, new_admin)
+            }
+            ExecuteMsg::SetPaused { paused } => Self::run_set_paused(deps, env, info, paused),
+            ExecuteMsg::ForceTransfer {
+                from,
+                to,
+                amount,
+                as_native,
+            } => Self::run_force_transfer(deps, env, info, from, to, amount, as_native),
+            ExecuteMsg::UpdateClawbackAdmin { new_admin } => {
+                Self::run_update_clawback_admin(deps, info, new_admin)
             }
+            ExecuteMsg::UpdateBlocklist { add, remove } => {
+                Self::run_update_blocklist(deps, env, info, add, remove)
+            }
+            ExecuteMsg::UpdateMarketing { .. } | ExecuteMsg::UploadLogo(..) => {
+                Self::run_update_marketing(deps, env, info, msg)
+            }
+            _ => Self::execute_base(deps, env, info, msg),
         }
     }
 
     pub fn query(deps: Deps<CQ>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         match msg {
             QueryMsg::FactoryDenom {} => FactoryDenom::load(deps.storage).into_binary(),
+            QueryMsg::NativeAdmin {} => Self::qy_native_admin(deps.storage).into_binary(),
+            QueryMsg::ClawbackAdmin {} => Self::qy_clawback_admin(deps.storage).into_binary(),
+            QueryMsg::Paused {} => Self::qy_paused(deps.storage).into_binary(),
+            QueryMsg::Blocklist { start_after, limit } => {
+                Self::qy_blocklist(deps.into_empty(), start_after, limit).into_binary()
+            }
+            QueryMsg::Indexers { start_after, limit } => {
+                Self::qy_indexers(deps.into_empty(), start_after, limit).into_binary()
+            }
+            QueryMsg::Health { start_after, limit } => {
+                Self::qy_health(deps.into_empty(), start_after, limit).into_binary()
+            }
+            QueryMsg::SupplyReconciliation {} => {
+                Self::qy_supply_reconciliation(deps.into_empty()).into_binary()
+            }
             QueryMsg::SupplyDetails {} => Self::qy_supply_details(deps.into_empty())
                 .into_std_result()
                 .into_binary(),
@@ -136,11 +259,77 @@ where
         }
     }
 
+    pub fn sudo(deps: DepsMut<CQ>, _env: Env, msg: SudoMsg) -> ContractResponse<CM> {
+        match msg {
+            SudoMsg::BlockBeforeSend { from, to, amount } => {
+                if let Some(pending) = PENDING_FORCE_TRANSFER.may_load(deps.storage)? {
+                    if pending.from == from && pending.to == to && pending.amount == amount {
+                        PENDING_FORCE_TRANSFER.remove(deps.storage);
+                        return Ok(Response::new()
+                            .add_attribute("action", "block_before_send")
+                            .add_attribute("force_transfer", "true"));
+                    }
+                }
+
+                Self::assert_not_restricted(deps.as_ref().into_empty(), &[&from, &to])?;
+                Ok(Response::new().add_attribute("action", "block_before_send"))
+            }
+            SudoMsg::TrackBeforeSend { .. } => Ok(Response::new()),
+        }
+    }
+
+    pub fn reply(deps: DepsMut<CQ>, env: Env, reply: Reply) -> ContractResponse<CM> {
+        match reply.id {
+            // Cleared even if the chain didn't call the before send hook
+            FORCE_TRANSFER_REPLY_ID => {
+                PENDING_FORCE_TRANSFER.remove(deps.storage);
+                Ok(Response::new().add_attribute("action", "force_transfer_reply"))
+            }
+            // An indexer that deregistered the denom can't block the token
+            INDEXER_REFRESH_REPLY_ID => {
+                Ok(Response::new().add_attribute("action", "indexer_refresh_failed"))
+            }
+            _ => I::reply(deps, &env, reply),
+        }
+    }
+
     pub fn migrate(deps: DepsMut<CQ>, env: Env, msg: MigrateMsg) -> ContractResponse<CM> {
-        let base: cw20_base::msg::MigrateMsg = msg.into_binary()?.des_into()?;
-        cw20_base::contract::migrate(deps.into_empty(), env.clone(), base)?
-            .to_custom()
-            .wrap_ok()
+        // Native tokens created before the supply was tracked
+        if let Ok(denom) = FactoryDenom::load(deps.storage) {
+            if !NATIVE_SUPPLY.exists(deps.storage) {
+                let supply = deps.querier.query_supply(denom.inner())?.amount;
+                NATIVE_SUPPLY.save(deps.storage, &supply)?;
+            }
+        }
+
+        // Native tokens created before the minted total was tracked
+        if !NATIVE_MINTED.exists(deps.storage) {
+            if let Some(supply) = NATIVE_SUPPLY.may_load(deps.storage)? {
+                NATIVE_MINTED.save(deps.storage, &supply)?;
+            }
+        }
+
+        if let Some(authority) = msg.create_native_authority {
+            CREATE_NATIVE_AUTHORITY.save(deps.storage, &authority)?;
+        }
+
+        if let Some(clawback_admin) = msg.clawback_admin {
+            if Self::qy_clawback_admin(deps.storage)? == ClawbackAdmin::Disabled {
+                return Err(Cw20FactoryError::ClawbackDisabled {});
+            }
+
+            CLAWBACK_ADMIN.save(
+                deps.storage,
+                &ClawbackAdmin::Enabled(clawback_admin.into_addr(deps.api)?),
+            )?;
+        }
+
+        cw20_base::contract::migrate(
+            deps.into_empty(),
+            env.clone(),
+            cw20_base::msg::MigrateMsg {},
+        )?
+        .to_custom_with(&I::map_msg)
     }
 }
 
@@ -157,27 +346,83 @@ where
         info: MessageInfo,
         into: TransmuteIntoMsg,
     ) -> ContractResponse<CM> {
+        Self::assert_native_admin(deps.storage)?;
+
         let (msgs, attrs) = match into {
-            TransmuteIntoMsg::Native { amount } => {
+            TransmuteIntoMsg::Native {
+                amount,
+                recipient,
+                hook,
+            } => {
+                let (receiver, hook_msg) =
+                    Self::transmute_receiver(deps.api, &info.sender, recipient, hook)?;
                 Self::burn_cw20(deps.branch().into_empty(), &info.sender, amount)?;
+                Self::validate_native_cap(deps.as_ref().into_empty(), amount)?;
                 let mint_coin = Coin::new(amount.u128(), FactoryDenom::load(deps.storage)?.inner());
+
+                let msgs = match hook_msg {
+                    Some(msg) => {
+                        let mut msgs = Self::mint_native(
+                            deps.branch(),
+                            &env,
+                            &info,
+                            &env.contract.address,
+                            &mint_coin,
+                        )?;
+                        msgs.push(
+                            WasmMsg::Execute {
+                                contract_addr: receiver.to_string(),
+                                msg,
+                                funds: vec![mint_coin.clone()],
+                            }
+                            .into(),
+                        );
+                        msgs
+                    }
+                    None => Self::mint_native(deps.branch(), &env, &info, &receiver, &mint_coin)?,
+                };
+
                 (
-                    I::mint(deps.branch(), &env, &info, &info.sender, &mint_coin)?,
+                    msgs,
                     vec![
                         attr("action", "transumte_into_native"),
                         attr("amount", mint_coin.amount),
+                        attr("recipient", receiver),
                     ],
                 )
             }
-            TransmuteIntoMsg::Cw20 {} => {
+            TransmuteIntoMsg::Cw20 { recipient, hook } => {
+                let (receiver, hook_msg) =
+                    Self::transmute_receiver(deps.api, &info.sender, recipient, hook)?;
                 let brun_coin = rhaki_cw_plus::asset::only_one_coin(&info.funds, None)?;
                 Self::assert_denom(deps.storage, &brun_coin)?;
-                Self::mint_cw20(deps.branch().into_empty(), &info.sender, brun_coin.amount)?;
+                Self::mint_cw20(deps.branch().into_empty(), &receiver, brun_coin.amount)?;
+                Self::validate_cw20_cap(deps.as_ref().into_empty())?;
+
+                let mut msgs = Self::burn_native(deps.branch(), &env, &info, &brun_coin)?;
+
+                if let Some(msg) = hook_msg {
+                    msgs.push(
+                        WasmMsg::Execute {
+                            contract_addr: receiver.to_string(),
+                            msg: Cw20ReceiveMsg {
+                                sender: info.sender.to_string(),
+                                amount: brun_coin.amount,
+                                msg,
+                            }
+                            .into_binary()?,
+                            funds: vec![],
+                        }
+                        .into(),
+                    );
+                }
+
                 (
-                    I::burn(deps.branch(), &env, &info, &brun_coin)?,
+                    msgs,
                     vec![
                         attr("action", "transumte_into_cw20"),
                         attr("amount", brun_coin.amount),
+                        attr("recipient", receiver),
                     ],
                 )
             }
@@ -189,6 +434,39 @@ where
             .wrap_ok()
     }
 
+    pub fn run_transmute_from(
+        mut deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        owner: String,
+        amount: Uint128,
+        recipient: String,
+    ) -> ContractResponse<CM> {
+        Self::assert_native_admin(deps.storage)?;
+        let owner = owner.into_addr(deps.api)?;
+        let recipient = recipient.into_addr(deps.api)?;
+
+        deduct_allowance(deps.storage, &owner, &info.sender, &env.block, amount)?;
+        Self::burn_cw20(deps.branch().into_empty(), &owner, amount)?;
+        Self::validate_native_cap(deps.as_ref().into_empty(), amount)?;
+
+        let mint_coin = Coin::new(amount.u128(), FactoryDenom::load(deps.storage)?.inner());
+
+        Response::new()
+            .add_attribute("action", "transmute_from_into_native")
+            .add_attribute("owner", owner)
+            .add_attribute("recipient", &recipient)
+            .add_attribute("amount", amount)
+            .add_messages(Self::mint_native(
+                deps.branch(),
+                &env,
+                &info,
+                &recipient,
+                &mint_coin,
+            )?)
+            .wrap_ok()
+    }
+
     pub fn run_mint(
         mut deps: DepsMut<CQ>,
         env: Env,
@@ -202,16 +480,19 @@ where
 
         let (msgs, action) = match as_native.unwrap_or(false) {
             true => {
+                Self::assert_native_admin(deps.storage)?;
                 let mint_coin = Coin::new(amount.u128(), FactoryDenom::load(deps.storage)?.inner());
                 Self::validate_max_supply(deps.as_ref().into_empty(), amount.wrap_some())?;
+                Self::validate_native_cap(deps.as_ref().into_empty(), amount)?;
                 (
-                    I::mint(deps.branch(), &env, &info, &recipient, &mint_coin)?,
+                    Self::mint_native(deps.branch(), &env, &info, &recipient, &mint_coin)?,
                     "mint_native",
                 )
             }
             false => {
                 Self::mint_cw20(deps.branch().into_empty(), &recipient, amount)?;
                 Self::validate_max_supply(deps.as_ref().into_empty(), None)?;
+                Self::validate_cw20_cap(deps.as_ref().into_empty())?;
                 (vec![], "mint_cw20")
             }
         };
@@ -237,10 +518,11 @@ where
                 vec![attr("action", "burn_cw20"), attr("amount", amount)],
             )
         } else {
+            Self::assert_native_admin(deps.storage)?;
             let denom = FactoryDenom::load(deps.storage)?.inner();
             let burn_coin = rhaki_cw_plus::asset::only_one_coin(&info.funds, Some(denom))?;
             (
-                I::burn(deps, &env, &info, &burn_coin)?,
+                Self::burn_native(deps, &env, &info, &burn_coin)?,
                 vec![
                     attr("action", "burn_native"),
                     attr("amount", burn_coin.amount),
@@ -254,14 +536,33 @@ where
             .wrap_ok()
     }
 
-    pub fn run_register_into_indexer(deps: DepsMut<CQ>, indexer: String) -> ContractResponse<CM> {
+    pub fn run_register_into_indexer(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        indexer: String,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
         let denom = FactoryDenom::load(deps.storage)?.inner();
+        let indexer_addr = indexer.clone().into_addr(deps.api)?;
+
+        if !INDEXERS.has(deps.storage, &indexer_addr)
+            && INDEXERS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .count()
+                >= MAX_INDEXERS as usize
+        {
+            return Err(Cw20FactoryError::TooManyIndexers { max: MAX_INDEXERS });
+        }
+
+        INDEXERS.save(deps.storage, &indexer_addr, &Empty {})?;
 
         Response::new()
             .add_attribute("action", "register_into_indexer")
             .add_attribute("indexer", &indexer)
             .add_message(WasmMsg::build_execute(
-                indexer.clone().into_addr(deps.api)?,
+                indexer_addr,
                 cw20_factory_pkg::cw20_indexer::msgs::ExecuteMsg::RegisterDenom(RegisterDenomMsg {
                     denom,
                 }),
@@ -270,26 +571,332 @@ where
             .wrap_ok()
     }
 
+    pub fn run_unregister_from_indexer(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        indexer: String,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
+        let denom = FactoryDenom::load(deps.storage)?.inner();
+        let indexer_addr = indexer.clone().into_addr(deps.api)?;
+
+        INDEXERS.remove(deps.storage, &indexer_addr);
+
+        Response::new()
+            .add_attribute("action", "unregister_from_indexer")
+            .add_attribute("indexer", &indexer)
+            .add_message(WasmMsg::build_execute(
+                indexer_addr,
+                cw20_factory_pkg::cw20_indexer::msgs::ExecuteMsg::DeregisterDenom { denom },
+                vec![],
+            )?)
+            .wrap_ok()
+    }
+
+    pub fn run_reconcile(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        flag: bool,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
+        let reconciliation = Self::qy_supply_reconciliation(deps.as_ref().into_empty())?;
+        let drift = reconciliation.tracked_supply != reconciliation.bank_supply;
+
+        if flag {
+            if drift {
+                FLAGGED_DRIFT.save(
+                    deps.storage,
+                    &SupplyDrift {
+                        tracked_supply: reconciliation.tracked_supply,
+                        bank_supply: reconciliation.bank_supply,
+                        height: env.block.height,
+                    },
+                )?;
+            } else {
+                FLAGGED_DRIFT.remove(deps.storage);
+            }
+        }
+
+        Response::new()
+            .add_attribute("action", "reconcile")
+            .add_attribute("tracked_supply", reconciliation.tracked_supply)
+            .add_attribute("bank_supply", reconciliation.bank_supply)
+            .add_attribute("drift", drift.to_string())
+            .wrap_ok()
+    }
+
+    pub fn run_update_supply_caps(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        native_cap: Option<SupplyCapUpdate>,
+        cw20_cap: Option<SupplyCapUpdate>,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
+        for (cap, update) in [(NATIVE_CAP, native_cap), (CW20_CAP, cw20_cap)] {
+            match update {
+                Some(SupplyCapUpdate::Set(amount)) => cap.save(deps.storage, &amount)?,
+                Some(SupplyCapUpdate::Remove {}) => cap.remove(deps.storage),
+                None => {}
+            }
+        }
+
+        Response::new()
+            .add_attribute("action", "update_supply_caps")
+            .add_attribute(
+                "native_cap",
+                format!("{:?}", NATIVE_CAP.may_load(deps.storage)?),
+            )
+            .add_attribute(
+                "cw20_cap",
+                format!("{:?}", CW20_CAP.may_load(deps.storage)?),
+            )
+            .wrap_ok()
+    }
+
+    pub fn run_refresh_indexers(
+        deps: DepsMut<CQ>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> ContractResponse<CM> {
+        let denom = FactoryDenom::load(deps.storage)?.inner();
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_after = start_after
+            .map(|address| deps.api.addr_validate(&address))
+            .transpose()?;
+
+        let msgs = INDEXERS
+            .keys(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|indexer| {
+                WasmMsg::build_execute(
+                    indexer?,
+                    cw20_factory_pkg::cw20_indexer::msgs::ExecuteMsg::RefreshDenom {
+                        denom: denom.clone(),
+                    },
+                    vec![],
+                )
+            })
+            .collect::<StdResult<Vec<_>>>()?;
+
+        Response::new()
+            .add_attribute("action", "refresh_indexers")
+            .add_attribute("indexers", msgs.len().to_string())
+            .add_messages(msgs)
+            .wrap_ok()
+    }
+
     pub fn run_create_native(
         mut deps: DepsMut<CQ>,
         env: Env,
         info: MessageInfo,
+        subdenom: Option<String>,
     ) -> ContractResponse<CM> {
+        Self::assert_create_native_authority(deps.as_ref().into_empty(), &env, &info.sender)?;
+
         let token_info = TOKEN_INFO.load(deps.storage)?;
         if FactoryDenom::load(deps.storage).is_ok() {
             return Err(Cw20FactoryError::NativeTokenAlredyCreated {});
         }
+
+        let refund_msgs = Self::collect_creation_fee(deps.as_ref(), &env, &info)?;
+
         let interface_response =
-            I::instantiate(deps.branch(), &env, info, token_info.symbol.clone())?;
+            I::instantiate(deps.branch(), &env, info, token_info.symbol, subdenom)?;
 
         let factory_denom = FactoryDenom::new(interface_response.factory_denom.clone());
         factory_denom.save(deps.storage)?;
+        NATIVE_SUPPLY.save(deps.storage, &Uint128::zero())?;
+
+        let hook_msgs =
+            I::set_before_send_hook(deps.branch(), &env, &interface_response.factory_denom)?;
+
+        let metadata_msgs =
+            Self::native_metadata_msgs(deps.branch(), &env, &interface_response.factory_denom)?;
 
         Response::new()
             .add_attribute("action", "create_native")
             .add_attributes(interface_response.attributes)
             .add_attribute("factory_denom", interface_response.factory_denom)
             .add_messages(interface_response.messages)
+            .add_messages(hook_msgs)
+            .add_messages(metadata_msgs)
+            .add_messages(refund_msgs)
+            .wrap_ok()
+    }
+
+    pub fn run_update_marketing(
+        mut deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> ContractResponse<CM> {
+        let response = Self::execute_base(deps.branch(), env.clone(), info, msg)?;
+
+        match FactoryDenom::load(deps.storage) {
+            Ok(denom) if Self::assert_native_admin(deps.storage).is_ok() => {
+                let metadata_msgs = Self::native_metadata_msgs(deps, &env, &denom.inner())?;
+                response.add_messages(metadata_msgs).wrap_ok()
+            }
+            _ => response.wrap_ok(),
+        }
+    }
+
+    pub fn run_update_native_admin(
+        mut deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        new_admin: Option<String>,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+        Self::assert_native_admin(deps.storage)?;
+
+        let denom = FactoryDenom::load(deps.storage)?.inner();
+        let new_admin = new_admin
+            .map(|admin| admin.into_addr(deps.api))
+            .transpose()?;
+
+        let msgs = I::change_admin(deps.branch(), &env, &denom, new_admin.as_ref())?;
+
+        let native_admin = match new_admin {
+            Some(admin) if admin == env.contract.address => NativeAdmin::Contract,
+            Some(admin) => NativeAdmin::Address(admin),
+            None => NativeAdmin::Renounced,
+        };
+
+        NATIVE_ADMIN.save(deps.storage, &native_admin)?;
+
+        Response::new()
+            .add_attribute("action", "update_native_admin")
+            .add_attribute("native_admin", format!("{native_admin:?}"))
+            .add_messages(msgs)
+            .wrap_ok()
+    }
+
+    pub fn run_set_paused(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        paused: bool,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
+        PAUSED.save(deps.storage, &paused)?;
+
+        Response::new()
+            .add_attribute("action", "set_paused")
+            .add_attribute("paused", paused.to_string())
+            .wrap_ok()
+    }
+
+    pub fn run_update_blocklist(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        add: Vec<String>,
+        remove: Vec<String>,
+    ) -> ContractResponse<CM> {
+        Self::assert_contract_admin(deps.as_ref().into_empty(), &env, &info.sender)?;
+
+        for address in &add {
+            BLOCKLIST.save(deps.storage, &address.into_addr(deps.api)?, &Empty {})?;
+        }
+
+        for address in &remove {
+            BLOCKLIST.remove(deps.storage, &address.into_addr(deps.api)?);
+        }
+
+        Response::new()
+            .add_attribute("action", "update_blocklist")
+            .add_attributes(add.iter().map(|address| attr("block", address)))
+            .add_attributes(remove.iter().map(|address| attr("unblock", address)))
+            .wrap_ok()
+    }
+
+    pub fn run_force_transfer(
+        mut deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        from: String,
+        to: String,
+        amount: Uint128,
+        as_native: Option<bool>,
+    ) -> ContractResponse<CM> {
+        Self::assert_clawback_admin(deps.storage, &info.sender)?;
+        let from = from.into_addr(deps.api)?;
+        let to = to.into_addr(deps.api)?;
+
+        let (msgs, action) = match as_native.unwrap_or(false) {
+            true => {
+                Self::assert_native_admin(deps.storage)?;
+                let coin = Coin::new(amount.u128(), FactoryDenom::load(deps.storage)?.inner());
+                PENDING_FORCE_TRANSFER.save(
+                    deps.storage,
+                    &PendingForceTransfer {
+                        from: from.clone(),
+                        to: to.clone(),
+                        amount: coin.clone(),
+                    },
+                )?;
+                let mut msgs: Vec<SubMsg<CM>> =
+                    I::force_transfer(deps.branch(), &env, &from, &to, &coin)?
+                        .into_iter()
+                        .map(SubMsg::new)
+                        .collect();
+
+                // The pending transfer is removed on the reply of the last message
+                match msgs.pop() {
+                    Some(last) => {
+                        msgs.push(SubMsg::reply_on_success(last.msg, FORCE_TRANSFER_REPLY_ID))
+                    }
+                    None => PENDING_FORCE_TRANSFER.remove(deps.storage),
+                }
+
+                (msgs, "force_transfer_native")
+            }
+            false => {
+                Self::modify_cw20_balance(deps.storage, &from, -Into::<Int256>::into(amount))?;
+                Self::modify_cw20_balance(deps.storage, &to, amount.into())?;
+                (vec![], "force_transfer_cw20")
+            }
+        };
+
+        Response::new()
+            .add_attribute("action", action)
+            .add_attribute("from", from)
+            .add_attribute("to", to)
+            .add_attribute("amount", amount)
+            .add_submessages(msgs)
+            .wrap_ok()
+    }
+
+    pub fn run_update_clawback_admin(
+        deps: DepsMut<CQ>,
+        info: MessageInfo,
+        new_admin: Option<String>,
+    ) -> ContractResponse<CM> {
+        Self::assert_clawback_admin(deps.storage, &info.sender)?;
+
+        let clawback_admin = match new_admin {
+            Some(admin) => ClawbackAdmin::Enabled(admin.into_addr(deps.api)?),
+            None => ClawbackAdmin::Disabled,
+        };
+
+        CLAWBACK_ADMIN.save(deps.storage, &clawback_admin)?;
+
+        Response::new()
+            .add_attribute("action", "update_clawback_admin")
+            .add_attribute("clawback_admin", format!("{clawback_admin:?}"))
             .wrap_ok()
     }
 }
@@ -301,6 +908,144 @@ where
     CQ: CustomQuery,
     I: TokenFactoryInterface<CQ, CM>,
 {
+    fn execute_base(
+        deps: DepsMut<CQ>,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> ContractResponse<CM> {
+        let base: Cw20ExecuteMsg = msg.into_binary()?.des_into()?;
+
+        cw20_base::contract::execute(deps.into_empty(), env, info, base)?
+            .to_custom_with(&I::map_msg)
+    }
+
+    /// Assert that `info.funds` cover the `tokenfactory` creation fee.
+    /// Returns the messages to refund the excess to the sender
+    fn collect_creation_fee(
+        deps: Deps<CQ>,
+        env: &Env,
+        info: &MessageInfo,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>> {
+        let mut funds = info.funds.clone();
+
+        for required in I::creation_fee(deps, env)? {
+            match funds.iter_mut().find(|coin| coin.denom == required.denom) {
+                Some(coin) if coin.amount >= required.amount => coin.amount -= required.amount,
+                received => {
+                    return Err(Cw20FactoryError::InsufficientCreationFee {
+                        received: received
+                            .map(|coin| coin.clone())
+                            .unwrap_or_else(|| Coin::new(0, required.denom.clone())),
+                        required,
+                    })
+                }
+            }
+        }
+
+        funds.retain(|coin| !coin.amount.is_zero());
+
+        if funds.is_empty() {
+            Ok(vec![])
+        } else {
+            Ok(vec![BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: funds,
+            }
+            .into()])
+        }
+    }
+
+    fn native_metadata_msgs(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>> {
+        let token_info = TOKEN_INFO.load(deps.storage)?;
+        let marketing = MARKETING_INFO.may_load(deps.storage)?;
+
+        let metadata = NativeMetadata {
+            name: token_info.name,
+            symbol: token_info.symbol,
+            decimals: token_info.decimals,
+            description: marketing
+                .as_ref()
+                .and_then(|marketing| marketing.description.clone()),
+            uri: marketing
+                .and_then(|marketing| marketing.logo)
+                .and_then(|logo| match logo {
+                    LogoInfo::Url(url) => url.wrap_some(),
+                    LogoInfo::Embedded => None,
+                }),
+        };
+
+        I::set_metadata(deps, env, denom, &metadata)
+    }
+
+    /// Mint the `native` token through the interface, tracking `NATIVE_SUPPLY`
+    fn mint_native(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        info: &MessageInfo,
+        to: &Addr,
+        coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>> {
+        let supply = NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
+        NATIVE_SUPPLY.save(deps.storage, &(supply + coin.amount))?;
+        let minted = NATIVE_MINTED.may_load(deps.storage)?.unwrap_or_default();
+        NATIVE_MINTED.save(deps.storage, &(minted + coin.amount))?;
+
+        I::mint(deps, env, info, to, coin)
+    }
+
+    /// Burn the `native` token through the interface, tracking `NATIVE_SUPPLY`.
+    ///
+    /// Saturates at zero, the drift is reported by `ExecuteMsg::Reconcile`
+    fn burn_native(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        info: &MessageInfo,
+        coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>> {
+        let supply = NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
+        NATIVE_SUPPLY.save(deps.storage, &supply.saturating_sub(coin.amount))?;
+        let burned = NATIVE_BURNED.may_load(deps.storage)?.unwrap_or_default();
+        NATIVE_BURNED.save(deps.storage, &(burned + coin.amount))?;
+
+        I::burn(deps, env, info, coin)
+    }
+
+    /// `cw20` and `native` supplies, compared before and after every execute
+    fn current_supplies(storage: &dyn Storage) -> StdResult<(Uint128, Uint128)> {
+        Ok((
+            TOKEN_INFO.load(storage)?.total_supply,
+            NATIVE_SUPPLY.may_load(storage)?.unwrap_or_default(),
+        ))
+    }
+
+    /// Refresh the cached details on every tracked indexer.
+    ///
+    /// Failed refreshes are replied and ignored
+    fn indexer_refresh_msgs(storage: &dyn Storage) -> StdResult<Vec<SubMsg<CM>>> {
+        let Ok(denom) = FactoryDenom::load(storage) else {
+            return Ok(vec![]);
+        };
+
+        INDEXERS
+            .keys(storage, None, None, Order::Ascending)
+            .map(|indexer| {
+                let msg = WasmMsg::build_execute(
+                    indexer?,
+                    cw20_factory_pkg::cw20_indexer::msgs::ExecuteMsg::RefreshDenom {
+                        denom: denom.inner(),
+                    },
+                    vec![],
+                )?;
+                Ok(SubMsg::reply_on_error(msg, INDEXER_REFRESH_REPLY_ID))
+            })
+            .collect()
+    }
+
     fn mint_cw20(deps: DepsMut, to: &Addr, amount: Uint128) -> ContractResult<()> {
         Self::modify_cw20_balance(deps.storage, to, amount.into())?;
         Self::modify_cw20_supply(deps, amount.into())
@@ -368,6 +1113,44 @@ where
         Ok(())
     }
 
+    fn validate_native_cap(deps: Deps, extra_amount: Uint128) -> ContractResult<()> {
+        if let Some(cap) = NATIVE_CAP.may_load(deps.storage)? {
+            let supply = NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
+            if supply + extra_amount > cap {
+                return Err(Cw20FactoryError::CannotExceedNativeCap { cap });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the current `cw20` supply, call it after minting
+    fn validate_cw20_cap(deps: Deps) -> ContractResult<()> {
+        if let Some(cap) = CW20_CAP.may_load(deps.storage)? {
+            if TOKEN_INFO.load(deps.storage)?.total_supply > cap {
+                return Err(Cw20FactoryError::CannotExceedCw20Cap { cap });
+            }
+        }
+        Ok(())
+    }
+
+    fn save_supply_caps(
+        storage: &mut dyn Storage,
+        native_cap: Option<Uint128>,
+        cw20_cap: Option<Uint128>,
+    ) -> StdResult<()> {
+        match native_cap {
+            Some(cap) => NATIVE_CAP.save(storage, &cap)?,
+            None => NATIVE_CAP.remove(storage),
+        }
+
+        match cw20_cap {
+            Some(cap) => CW20_CAP.save(storage, &cap)?,
+            None => CW20_CAP.remove(storage),
+        }
+
+        Ok(())
+    }
+
     fn assert_minter(deps: Deps, sender: &Addr) -> ContractResult<()> {
         let token_info = TOKEN_INFO.load(deps.storage)?;
 
@@ -384,6 +1167,225 @@ where
         }
     }
 
+    /// Addresses sending or receiving tokens with `msg`.
+    /// Returns `None` if `msg` doesn't move any token
+    fn involved_addresses<'a>(sender: &'a str, msg: &'a ExecuteMsg) -> Option<Vec<&'a str>> {
+        match msg {
+            ExecuteMsg::TransmuteInto(
+                TransmuteIntoMsg::Cw20 { recipient, hook }
+                | TransmuteIntoMsg::Native {
+                    recipient, hook, ..
+                },
+            ) => {
+                let mut addresses = vec![sender];
+                addresses.extend(recipient.as_deref());
+                addresses.extend(hook.as_ref().map(|hook| hook.contract.as_str()));
+                addresses
+            }
+            ExecuteMsg::Burn { .. } => vec![sender],
+            ExecuteMsg::TransmuteFrom {
+                owner, recipient, ..
+            } => vec![sender, owner, recipient],
+            ExecuteMsg::Transfer { recipient, .. } => vec![sender, recipient],
+            ExecuteMsg::Send { contract, .. } => vec![sender, contract],
+            ExecuteMsg::TransferFrom {
+                owner, recipient, ..
+            } => vec![sender, owner, recipient],
+            ExecuteMsg::SendFrom {
+                owner, contract, ..
+            } => vec![sender, owner, contract],
+            ExecuteMsg::BurnFrom { owner, .. } => vec![sender, owner],
+            ExecuteMsg::Mint { recipient, .. } => vec![recipient.as_str()],
+            _ => return None,
+        }
+        .wrap_some()
+    }
+
+    /// A `Send` to the contract itself is a transmute request,
+    /// otherwise `cw20_base` would call `Receive` on the contract and fail
+    fn self_send_into_transmute(env: &Env, msg: ExecuteMsg) -> ContractResult<ExecuteMsg> {
+        match msg {
+            ExecuteMsg::Send {
+                contract,
+                amount,
+                msg,
+            } if contract == env.contract.address.as_str() => match msg.des_into()? {
+                Cw20HookMsg::TransmuteIntoNative { recipient, hook } => {
+                    ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Native {
+                        amount,
+                        recipient,
+                        hook,
+                    })
+                    .wrap_ok()
+                }
+            },
+            msg => msg.wrap_ok(),
+        }
+    }
+
+    /// Resolve the receiver of the transmuted tokens and the optional hook msg
+    fn transmute_receiver(
+        api: &dyn Api,
+        sender: &Addr,
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    ) -> ContractResult<(Addr, Option<Binary>)> {
+        match (recipient, hook) {
+            (Some(_), Some(_)) => Err(Cw20FactoryError::RecipientAndHook {}),
+            (Some(recipient), None) => (recipient.into_addr(api)?, None).wrap_ok(),
+            (None, Some(hook)) => (hook.contract.into_addr(api)?, hook.msg.wrap_some()).wrap_ok(),
+            (None, None) => (sender.clone(), None).wrap_ok(),
+        }
+    }
+
+    fn assert_not_restricted(deps: Deps, addresses: &[&str]) -> ContractResult<()> {
+        if Self::qy_paused(deps.storage)? {
+            return Err(Cw20FactoryError::Paused {});
+        }
+
+        for address in addresses {
+            if BLOCKLIST.has(deps.storage, &deps.api.addr_validate(address)?) {
+                return Err(Cw20FactoryError::AddressBlocked {
+                    address: address.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn assert_clawback_admin(storage: &dyn Storage, sender: &Addr) -> ContractResult<()> {
+        match Self::qy_clawback_admin(storage)? {
+            ClawbackAdmin::Enabled(admin) if admin == sender => Ok(()),
+            ClawbackAdmin::Enabled(_) => Err(Cw20FactoryError::Unauthorized {
+                role: "ClawbackAdmin".to_string(),
+            }),
+            ClawbackAdmin::NotSet | ClawbackAdmin::Disabled => {
+                Err(Cw20FactoryError::ClawbackDisabled {})
+            }
+        }
+    }
+
+    fn assert_native_admin(storage: &dyn Storage) -> ContractResult<()> {
+        match Self::qy_native_admin(storage)? {
+            NativeAdmin::Contract => Ok(()),
+            admin => Err(Cw20FactoryError::NativeAdminNotOwned {
+                admin: format!("{admin:?}"),
+            }),
+        }
+    }
+
+    fn assert_contract_admin(deps: Deps, env: &Env, sender: &Addr) -> ContractResult<()> {
+        let admin = deps
+            .querier
+            .query_wasm_contract_info(&env.contract.address)?
+            .admin;
+
+        if admin.as_deref() != Some(sender.as_str()) {
+            return Err(Cw20FactoryError::Unauthorized {
+                role: "contract admin".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn assert_create_native_authority(deps: Deps, env: &Env, sender: &Addr) -> ContractResult<()> {
+        let authority = CREATE_NATIVE_AUTHORITY
+            .may_load(deps.storage)?
+            .unwrap_or_default();
+
+        let allowed = match authority {
+            CreateNativeAuthority::Minter => TOKEN_INFO
+                .load(deps.storage)?
+                .mint
+                .map(|minter| minter.minter.to_string()),
+            CreateNativeAuthority::Marketing => MARKETING_INFO
+                .may_load(deps.storage)?
+                .and_then(|marketing| marketing.marketing)
+                .map(|marketing| marketing.to_string()),
+            CreateNativeAuthority::ContractAdmin => {
+                deps.querier
+                    .query_wasm_contract_info(&env.contract.address)?
+                    .admin
+            }
+        };
+
+        if allowed.as_deref() != Some(sender.as_str()) {
+            return Err(Cw20FactoryError::Unauthorized {
+                role: format!("{authority:?}"),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn qy_native_admin(storage: &dyn Storage) -> StdResult<NativeAdmin> {
+        NATIVE_ADMIN
+            .may_load(storage)?
+            .unwrap_or(NativeAdmin::Contract)
+            .wrap_ok()
+    }
+
+    fn qy_clawback_admin(storage: &dyn Storage) -> StdResult<ClawbackAdmin> {
+        CLAWBACK_ADMIN
+            .may_load(storage)?
+            .unwrap_or_default()
+            .wrap_ok()
+    }
+
+    fn qy_paused(storage: &dyn Storage) -> StdResult<bool> {
+        PAUSED.may_load(storage)?.unwrap_or_default().wrap_ok()
+    }
+
+    fn qy_blocklist(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<BlocklistResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_after = start_after
+            .map(|address| deps.api.addr_validate(&address))
+            .transpose()?;
+
+        let addresses = BLOCKLIST
+            .keys(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|address| address.map(|address| address.to_string()))
+            .collect::<StdResult<_>>()?;
+
+        BlocklistResponse { addresses }.wrap_ok()
+    }
+
+    fn qy_indexers(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<IndexersResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start_after = start_after
+            .map(|address| deps.api.addr_validate(&address))
+            .transpose()?;
+
+        let indexers = INDEXERS
+            .keys(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .map(|address| address.map(|address| address.to_string()))
+            .collect::<StdResult<_>>()?;
+
+        IndexersResponse { indexers }.wrap_ok()
+    }
+
     fn qy_token_info(deps: Deps) -> ContractResult<TokenInfoResponse> {
         let info = TOKEN_INFO.load(deps.storage)?;
         let supply = Self::qy_supply_details(deps)?.total_supply;
@@ -395,18 +1397,75 @@ where
         })
     }
 
+    fn qy_health(
+        deps: Deps,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<HealthResponse> {
+        let limit = limit.unwrap_or(HEALTH_DEFAULT_LIMIT).min(HEALTH_MAX_LIMIT) as usize;
+        let start_after = start_after
+            .map(|address| deps.api.addr_validate(&address))
+            .transpose()?;
+
+        let balances = BALANCES
+            .range(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit)
+            .collect::<StdResult<Vec<_>>>()?;
+
+        let token_info = TOKEN_INFO.load(deps.storage)?;
+        let tracked_native_supply = NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
+
+        let bank_native_supply = match FactoryDenom::load(deps.storage) {
+            Ok(denom) => deps.querier.query_supply(denom.inner())?.amount,
+            Err(_) => Uint128::zero(),
+        };
+
+        let cap_headroom = token_info
+            .get_cap()
+            .map(|cap| cap.saturating_sub(token_info.total_supply + tracked_native_supply));
+
+        HealthResponse {
+            cw20_supply: token_info.total_supply,
+            balances_sum: balances.iter().map(|(_, balance)| balance).sum(),
+            last_address: balances
+                .last()
+                .filter(|_| balances.len() == limit)
+                .map(|(address, _)| address.to_string()),
+            tracked_native_supply,
+            native_minted: NATIVE_MINTED.may_load(deps.storage)?.unwrap_or_default(),
+            native_burned: NATIVE_BURNED.may_load(deps.storage)?.unwrap_or_default(),
+            bank_native_supply,
+            cap_headroom,
+        }
+        .wrap_ok()
+    }
+
+    fn qy_supply_reconciliation(deps: Deps) -> StdResult<SupplyReconciliationResponse> {
+        let denom = FactoryDenom::load(deps.storage)?.inner();
+
+        SupplyReconciliationResponse {
+            tracked_supply: NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default(),
+            bank_supply: deps.querier.query_supply(denom)?.amount,
+            flagged: FLAGGED_DRIFT.may_load(deps.storage)?,
+        }
+        .wrap_ok()
+    }
+
     fn qy_supply_details(deps: Deps) -> ContractResult<SupplyDetailsResponse> {
         let cw20_supply = TOKEN_INFO.load(deps.storage)?.total_supply;
-        let native_supply = if let Ok(denom) = FactoryDenom::load(deps.storage) {
-            deps.querier.query_supply(denom.inner())?.amount
-        } else {
-            Uint128::zero()
-        };
+        let native_supply = NATIVE_SUPPLY.may_load(deps.storage)?.unwrap_or_default();
 
         SupplyDetailsResponse {
             cw20_supply,
             native_supply,
             total_supply: cw20_supply + native_supply,
+            native_cap: NATIVE_CAP.may_load(deps.storage)?,
+            cw20_cap: CW20_CAP.may_load(deps.storage)?,
         }
         .wrap_ok()
     }
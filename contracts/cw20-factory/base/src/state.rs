@@ -1,7 +1,60 @@
 use cosmwasm_schema::cw_serde;
 
+use cosmwasm_std::{Addr, Coin, Empty, Uint128};
+use cw20_factory_pkg::cw20_factory::definitions::{
+    ClawbackAdmin, CreateNativeAuthority, NativeAdmin, SupplyDrift,
+};
+use cw_storage_plus::{Item, Map};
 use rhaki_cw_plus::storage::interfaces::ItemInterface;
 
+pub const CREATE_NATIVE_AUTHORITY: Item<CreateNativeAuthority> =
+    Item::new("create_native_authority");
+
+/// If not saved, `NativeAdmin::Contract` is assumed
+pub const NATIVE_ADMIN: Item<NativeAdmin> = Item::new("native_admin");
+
+pub const PAUSED: Item<bool> = Item::new("paused");
+
+pub const BLOCKLIST: Map<&Addr, Empty> = Map::new("blocklist");
+
+/// Indexers this contract registered into, refreshed on supply and marketing changes
+/// and by `ExecuteMsg::RefreshIndexers`.
+/// Registrations done before the tracking was introduced are missing
+pub const INDEXERS: Map<&Addr, Empty> = Map::new("indexers");
+
+pub const CLAWBACK_ADMIN: Item<ClawbackAdmin> = Item::new("clawback_admin");
+
+/// `native` supply minted by the contract minus the burned one.
+/// Saved on native creation, or from the bank supply on migration
+pub const NATIVE_SUPPLY: Item<Uint128> = Item::new("native_supply");
+
+/// `native` minted by the contract, transmutes included.
+/// Initialized from the bank supply on migration
+pub const NATIVE_MINTED: Item<Uint128> = Item::new("native_minted");
+
+/// `native` burned by the contract, transmutes included
+pub const NATIVE_BURNED: Item<Uint128> = Item::new("native_burned");
+
+/// Max `native` supply, enforced on mint and transmute
+pub const NATIVE_CAP: Item<Uint128> = Item::new("native_cap");
+
+/// Max `cw20` supply, enforced on mint and transmute
+pub const CW20_CAP: Item<Uint128> = Item::new("cw20_cap");
+
+/// Drift flagged by `ExecuteMsg::Reconcile`
+pub const FLAGGED_DRIFT: Item<SupplyDrift> = Item::new("flagged_drift");
+
+/// `native` force transfer waiting for `SudoMsg::BlockBeforeSend`.
+/// Allowed to bypass pause and blocklist
+pub const PENDING_FORCE_TRANSFER: Item<PendingForceTransfer> = Item::new("pending_force_transfer");
+
+#[cw_serde]
+pub struct PendingForceTransfer {
+    pub from: Addr,
+    pub to: Addr,
+    pub amount: Coin,
+}
+
 #[cw_serde]
 pub struct FactoryDenom(String);
 
@@ -0,0 +1,74 @@
+//! Subset of the `coreum-wasm-sdk` bindings used by the contract (`assetft` module only).
+//!
+//! Variants and fields mirror `coreum-wasm-sdk`, so the json is the same one expected by the chain.
+
+use cosmwasm_schema::{
+    cw_serde,
+    serde::{Deserialize, Serialize},
+};
+use cosmwasm_std::{Coin, CosmosMsg, CustomMsg, CustomQuery, Uint128};
+
+/// `assetft` feature allowing the admin to mint after the issue
+pub const FEATURE_MINTING: u32 = 0;
+
+#[cw_serde]
+pub enum CoreumMsg {
+    AssetFT(AssetFTMsg),
+}
+
+impl CustomMsg for CoreumMsg {}
+
+impl From<AssetFTMsg> for CosmosMsg<CoreumMsg> {
+    fn from(msg: AssetFTMsg) -> Self {
+        CosmosMsg::Custom(CoreumMsg::AssetFT(msg))
+    }
+}
+
+#[cw_serde]
+pub enum AssetFTMsg {
+    /// Issue `{subunit}-{sender}`, the issue fee is burned from the sender
+    Issue {
+        symbol: String,
+        subunit: String,
+        precision: u32,
+        initial_amount: Uint128,
+        description: Option<String>,
+        features: Option<Vec<u32>>,
+        burn_rate: Option<String>,
+        send_commission_rate: Option<String>,
+        uri: Option<String>,
+        uri_hash: Option<String>,
+    },
+    /// A missing `recipient` mints to the sender
+    Mint {
+        coin: Coin,
+        recipient: Option<String>,
+    },
+    /// Burn from the sender
+    Burn { coin: Coin },
+}
+
+#[cw_serde]
+pub enum CoreumQueries {
+    AssetFT(AssetFTQuery),
+}
+
+impl CustomQuery for CoreumQueries {}
+
+#[cw_serde]
+pub enum AssetFTQuery {
+    Params {},
+}
+
+/// `Params` carries other fields not needed by the contract, so unknown fields are allowed
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct ParamsResponse {
+    pub params: Params,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct Params {
+    pub issue_fee: Coin,
+}
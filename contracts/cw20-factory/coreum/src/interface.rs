@@ -0,0 +1,177 @@
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest, Uint128};
+use cw20_base::state::TOKEN_INFO;
+use cw20_factory_pkg::cw20_factory::{
+    interface::{InterfaceInstantiateResponse, NativeMetadata, TokenFactoryInterface},
+    ContractResult, Cw20FactoryError,
+};
+use rhaki_cw_plus::traits::Wrapper;
+
+use crate::bindings::{
+    AssetFTMsg, AssetFTQuery, CoreumMsg, CoreumQueries, ParamsResponse, FEATURE_MINTING,
+};
+
+/// Max length of a subunit on the `Coreum` `assetft` module
+pub const MAX_SUBUNIT_LENGTH: usize = 51;
+
+pub struct CoreumTokenFactoryInterface {}
+
+impl TokenFactoryInterface<CoreumQueries, CoreumMsg> for CoreumTokenFactoryInterface {
+    /// The `subdenom` is used as `subunit`. The precision is taken from the `cw20` token info
+    fn instantiate(
+        deps: DepsMut<CoreumQueries>,
+        env: &Env,
+        _info: MessageInfo,
+        symbol: String,
+        subdenom: Option<String>,
+    ) -> ContractResult<InterfaceInstantiateResponse<CoreumMsg>> {
+        let subunit = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_subunit(&subunit)?;
+
+        let token_info = TOKEN_INFO.load(deps.storage)?;
+
+        let msg = AssetFTMsg::Issue {
+            symbol,
+            subunit: subunit.clone(),
+            precision: token_info.decimals.into(),
+            initial_amount: Uint128::zero(),
+            description: None,
+            features: vec![FEATURE_MINTING].wrap_some(),
+            burn_rate: None,
+            send_commission_rate: None,
+            uri: None,
+            uri_hash: None,
+        }
+        .into();
+
+        Ok(InterfaceInstantiateResponse {
+            attributes: vec![],
+            messages: vec![msg],
+            factory_denom: denom(&env.contract.address, &subunit),
+        })
+    }
+
+    fn creation_fee(deps: Deps<CoreumQueries>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        let issue_fee = deps
+            .querier
+            .query::<ParamsResponse>(&QueryRequest::Custom(CoreumQueries::AssetFT(
+                AssetFTQuery::Params {},
+            )))?
+            .params
+            .issue_fee;
+
+        if issue_fee.amount.is_zero() {
+            Ok(vec![])
+        } else {
+            Ok(vec![issue_fee])
+        }
+    }
+
+    fn burn(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _info: &MessageInfo,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        vec![AssetFTMsg::Burn {
+            coin: amount.clone(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn mint(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _info: &MessageInfo,
+        to: &Addr,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        vec![AssetFTMsg::Mint {
+            coin: amount.clone(),
+            recipient: to.to_string().wrap_some(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    /// The `assetft` bindings have no admin transfer
+    fn change_admin(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _denom: &str,
+        _new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        Err(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "admin change".to_string(),
+        })
+    }
+
+    /// The `assetft` bindings have no force transfer
+    fn force_transfer(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _from: &Addr,
+        _to: &Addr,
+        _coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        Err(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "force transfer".to_string(),
+        })
+    }
+
+    /// The `assetft` module has no before send hook
+    fn set_before_send_hook(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        Ok(vec![])
+    }
+
+    /// `assetft` metadata is fixed at issue time
+    fn set_metadata(
+        _deps: DepsMut<CoreumQueries>,
+        _env: &Env,
+        _denom: &str,
+        _metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<CoreumMsg>>> {
+        Ok(vec![])
+    }
+}
+
+/// `assetft` denoms are `{subunit}-{issuer}`, always lowercase
+fn denom(issuer: &Addr, subunit: &str) -> String {
+    format!("{}-{}", subunit, issuer).to_lowercase()
+}
+
+/// Validate a `subunit` against the `assetft` rules:
+/// - starts with a lowercase letter and is at most `MAX_SUBUNIT_LENGTH` characters;
+/// - only `[a-z0-9/:._]` characters.
+fn validate_subunit(subunit: &str) -> ContractResult<()> {
+    let invalid = |reason: String| Cw20FactoryError::InvalidSubdenom {
+        subdenom: subunit.to_string(),
+        reason,
+    };
+
+    match subunit.chars().next() {
+        None => return Err(invalid("subunit is empty".to_string())),
+        Some(first) if !first.is_ascii_lowercase() => {
+            return Err(invalid("must start with a lowercase letter".to_string()))
+        }
+        _ => {}
+    }
+
+    if subunit.len() > MAX_SUBUNIT_LENGTH {
+        return Err(invalid(format!(
+            "length exceeds {MAX_SUBUNIT_LENGTH} characters"
+        )));
+    }
+
+    if let Some(char) = subunit.chars().find(|char| {
+        !(char.is_ascii_lowercase() || char.is_ascii_digit() || "/:._".contains(*char))
+    }) {
+        return Err(invalid(format!("invalid character '{char}'")));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use bindings::{CoreumMsg, CoreumQueries};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, StdResult};
+use cw20_factory_base::contract::Cw20FactoryBase;
+use cw20_factory_pkg::cw20_factory::{
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    ContractResponse,
+};
+use interface::CoreumTokenFactoryInterface;
+
+pub mod bindings;
+pub mod interface;
+
+pub type Cw20FactoryCoreum = Cw20FactoryBase<CoreumQueries, CoreumTokenFactoryInterface, CoreumMsg>;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResponse<CoreumMsg> {
+    Cw20FactoryCoreum::instantiate(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResponse<CoreumMsg> {
+    Cw20FactoryCoreum::execute(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn query(deps: Deps<CoreumQueries>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    Cw20FactoryCoreum::query(deps, env, msg)
+}
+
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    msg: MigrateMsg,
+) -> ContractResponse<CoreumMsg> {
+    Cw20FactoryCoreum::migrate(deps, env, msg)
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut<CoreumQueries>, env: Env, reply: Reply) -> ContractResponse<CoreumMsg> {
+    Cw20FactoryCoreum::reply(deps, env, reply)
+}
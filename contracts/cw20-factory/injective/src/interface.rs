@@ -0,0 +1,172 @@
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, QueryRequest,
+};
+use cw20_factory_pkg::cw20_factory::{
+    interface::{
+        validate_tokenfactory_subdenom, InterfaceInstantiateResponse, NativeMetadata,
+        TokenFactoryInterface,
+    },
+    ContractResult, Cw20FactoryError,
+};
+use rhaki_cw_plus::traits::Wrapper;
+
+use crate::proto::{
+    Metadata, MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+    QueryParamsResponse, TypeUrl, QUERY_PARAMS_PATH,
+};
+
+/// Max length of a subdenom on the `Injective` `tokenfactory`
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+pub struct InjectiveTokenFactoryInterface {}
+
+impl TokenFactoryInterface for InjectiveTokenFactoryInterface {
+    fn instantiate(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: MessageInfo,
+        symbol: String,
+        subdenom: Option<String>,
+    ) -> ContractResult<InterfaceInstantiateResponse<Empty>> {
+        let subdenom = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_tokenfactory_subdenom(&subdenom, MAX_SUBDENOM_LENGTH)?;
+
+        // name and decimals are set right after with `MsgSetDenomMetadata`
+        let msg = MsgCreateDenom {
+            sender: env.contract.address.to_string(),
+            subdenom: subdenom.clone(),
+            name: "".to_string(),
+            symbol,
+            decimals: 0,
+        }
+        .into_stargate();
+
+        Ok(InterfaceInstantiateResponse {
+            attributes: vec![],
+            messages: vec![msg],
+            factory_denom: denom(&env.contract.address, subdenom),
+        })
+    }
+
+    fn creation_fee(deps: Deps<Empty>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        deps.querier
+            .query::<QueryParamsResponse>(&QueryRequest::Stargate {
+                path: QUERY_PARAMS_PATH.to_string(),
+                data: Default::default(),
+            })?
+            .params
+            .denom_creation_fee
+            .wrap_ok()
+    }
+
+    /// `MsgBurn` always burns from the sender, which is the contract itself
+    fn burn(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: &MessageInfo,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        vec![MsgBurn {
+            sender: env.contract.address.to_string(),
+            amount: Some(amount.clone().into()),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+
+    /// `MsgMint` always mints to the sender, so the minted amount is sent to `to` afterwards
+    fn mint(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: &MessageInfo,
+        to: &Addr,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let mint_msg = MsgMint {
+            sender: env.contract.address.to_string(),
+            amount: Some(amount.clone().into()),
+        }
+        .into_stargate();
+
+        let send_msg = BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![amount.clone()],
+        }
+        .into();
+
+        vec![mint_msg, send_msg].wrap_ok()
+    }
+
+    fn change_admin(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        vec![MsgChangeAdmin {
+            sender: env.contract.address.to_string(),
+            denom: denom.to_string(),
+            new_admin: new_admin.map(|admin| admin.to_string()).unwrap_or_default(),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+
+    /// The `Injective` `tokenfactory` has no force transfer
+    fn force_transfer(
+        _deps: DepsMut<Empty>,
+        _env: &Env,
+        _from: &Addr,
+        _to: &Addr,
+        _coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        Err(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "force transfer".to_string(),
+        })
+    }
+
+    /// The `Injective` `tokenfactory` has no before send hook,
+    /// so pause and blocklist are enforced only on the `cw20` side and on transmute
+    fn set_before_send_hook(
+        _deps: DepsMut<Empty>,
+        _env: &Env,
+        _denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        Ok(vec![])
+    }
+
+    fn set_metadata(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        vec![MsgSetDenomMetadata {
+            sender: env.contract.address.to_string(),
+            metadata: bank_metadata(denom, metadata).wrap_some(),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+}
+
+fn denom(contract: &Addr, subdenom: String) -> String {
+    format!("factory/{}/{}", contract, subdenom)
+}
+
+/// Build the bank `Metadata`, with `decimals` also set on the `Injective` field
+fn bank_metadata(denom: &str, metadata: &NativeMetadata) -> Metadata {
+    let (denom_units, display) = metadata.denom_units(denom);
+
+    Metadata {
+        description: metadata.description.clone().unwrap_or_default(),
+        denom_units: denom_units.into_iter().map(Into::into).collect(),
+        base: denom.to_string(),
+        display,
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone().unwrap_or_default(),
+        uri_hash: "".to_string(),
+        decimals: metadata.decimals.into(),
+    }
+}
@@ -0,0 +1,52 @@
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, StdResult};
+use cw20_factory_base::contract::Cw20FactoryBase;
+use cw20_factory_pkg::cw20_factory::{
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+    ContractResponse,
+};
+use interface::InjectiveTokenFactoryInterface;
+
+pub mod interface;
+pub mod proto;
+
+pub type Cw20FactoryInjective = Cw20FactoryBase<Empty, InjectiveTokenFactoryInterface, Empty>;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResponse<Empty> {
+    Cw20FactoryInjective::instantiate(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResponse<Empty> {
+    Cw20FactoryInjective::execute(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    Cw20FactoryInjective::query(deps, env, msg)
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResponse<Empty> {
+    Cw20FactoryInjective::sudo(deps, env, msg)
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> ContractResponse<Empty> {
+    Cw20FactoryInjective::migrate(deps, env, msg)
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResponse<Empty> {
+    Cw20FactoryInjective::reply(deps, env, reply)
+}
@@ -0,0 +1,114 @@
+//! `injective.tokenfactory.v1beta1` messages used by the contract.
+//!
+//! Only the fields the contract sets are declared, unknown fields are ignored by the chain.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Coin as StdCoin;
+use prost::Message;
+
+pub use cw20_factory_pkg::cw20_factory::proto::{Coin, DenomUnit, TypeUrl};
+
+pub const QUERY_PARAMS_PATH: &str = "/injective.tokenfactory.v1beta1.Query/Params";
+
+/// `cosmos.bank.v1beta1.Metadata` extended with the Injective `decimals` field
+#[derive(Clone, PartialEq, Message)]
+pub struct Metadata {
+    #[prost(string, tag = "1")]
+    pub description: String,
+    #[prost(message, repeated, tag = "2")]
+    pub denom_units: Vec<DenomUnit>,
+    #[prost(string, tag = "3")]
+    pub base: String,
+    #[prost(string, tag = "4")]
+    pub display: String,
+    #[prost(string, tag = "5")]
+    pub name: String,
+    #[prost(string, tag = "6")]
+    pub symbol: String,
+    #[prost(string, tag = "7")]
+    pub uri: String,
+    #[prost(string, tag = "8")]
+    pub uri_hash: String,
+    #[prost(uint32, tag = "9")]
+    pub decimals: u32,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+    #[prost(string, tag = "3")]
+    pub name: String,
+    #[prost(string, tag = "4")]
+    pub symbol: String,
+    #[prost(uint32, tag = "5")]
+    pub decimals: u32,
+}
+
+impl TypeUrl for MsgCreateDenom {
+    const TYPE_URL: &'static str = "/injective.tokenfactory.v1beta1.MsgCreateDenom";
+}
+
+/// Mint `amount` to `sender`
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgMint {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+}
+
+impl TypeUrl for MsgMint {
+    const TYPE_URL: &'static str = "/injective.tokenfactory.v1beta1.MsgMint";
+}
+
+/// Burn `amount` from `sender`
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgBurn {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+}
+
+impl TypeUrl for MsgBurn {
+    const TYPE_URL: &'static str = "/injective.tokenfactory.v1beta1.MsgBurn";
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub denom: String,
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+impl TypeUrl for MsgChangeAdmin {
+    const TYPE_URL: &'static str = "/injective.tokenfactory.v1beta1.MsgChangeAdmin";
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSetDenomMetadata {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: Option<Metadata>,
+}
+
+impl TypeUrl for MsgSetDenomMetadata {
+    const TYPE_URL: &'static str = "/injective.tokenfactory.v1beta1.MsgSetDenomMetadata";
+}
+
+#[cw_serde]
+pub struct QueryParamsResponse {
+    pub params: Params,
+}
+
+#[cw_serde]
+pub struct Params {
+    pub denom_creation_fee: Vec<StdCoin>,
+}
@@ -0,0 +1,73 @@
+//! Subset of the `kujira` bindings used by the contract (`denom` module only).
+//!
+//! Variants and fields mirror `kujira-rs`, so the json is the same one expected by the chain.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, CosmosMsg, CustomMsg, CustomQuery, Uint128};
+
+pub const QUERY_DENOM_PARAMS_PATH: &str = "/kujira.denom.Query/Params";
+
+#[cw_serde]
+pub enum KujiraMsg {
+    Denom(DenomMsg),
+}
+
+impl CustomMsg for KujiraMsg {}
+
+impl From<DenomMsg> for CosmosMsg<KujiraMsg> {
+    fn from(msg: DenomMsg) -> Self {
+        CosmosMsg::Custom(KujiraMsg::Denom(msg))
+    }
+}
+
+#[cw_serde]
+pub enum DenomMsg {
+    /// Create `factory/{sender}/{subdenom}`, the creation fee is charged to the sender
+    Create {
+        subdenom: String,
+    },
+    ChangeAdmin {
+        denom: String,
+        address: Addr,
+    },
+    Mint {
+        denom: String,
+        amount: Uint128,
+        recipient: Addr,
+    },
+    /// Burn from the sender
+    Burn {
+        denom: String,
+        amount: Uint128,
+    },
+}
+
+#[cw_serde]
+pub enum KujiraQuery {
+    Denom(DenomQuery),
+}
+
+impl CustomQuery for KujiraQuery {}
+
+#[cw_serde]
+pub enum DenomQuery {
+    FullDenom {
+        creator_addr: Addr,
+        subdenom: String,
+    },
+}
+
+#[cw_serde]
+pub struct FullDenomResponse {
+    pub denom: String,
+}
+
+#[cw_serde]
+pub struct QueryDenomParamsResponse {
+    pub params: DenomParams,
+}
+
+#[cw_serde]
+pub struct DenomParams {
+    pub creation_fee: Vec<Coin>,
+}
@@ -0,0 +1,140 @@
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest};
+use cw20_factory_pkg::cw20_factory::{
+    interface::{
+        validate_tokenfactory_subdenom, InterfaceInstantiateResponse, NativeMetadata,
+        TokenFactoryInterface,
+    },
+    ContractResult, Cw20FactoryError,
+};
+use rhaki_cw_plus::traits::Wrapper;
+
+use crate::bindings::{
+    DenomMsg, DenomQuery, FullDenomResponse, KujiraMsg, KujiraQuery, QueryDenomParamsResponse,
+    QUERY_DENOM_PARAMS_PATH,
+};
+
+/// Max length of a subdenom on the `Kujira` `denom` module
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+pub struct KujiraTokenFactoryInterface {}
+
+impl TokenFactoryInterface<KujiraQuery, KujiraMsg> for KujiraTokenFactoryInterface {
+    fn instantiate(
+        deps: DepsMut<KujiraQuery>,
+        env: &Env,
+        _info: MessageInfo,
+        symbol: String,
+        subdenom: Option<String>,
+    ) -> ContractResult<InterfaceInstantiateResponse<KujiraMsg>> {
+        let subdenom = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_tokenfactory_subdenom(&subdenom, MAX_SUBDENOM_LENGTH)?;
+
+        let factory_denom = deps
+            .querier
+            .query::<FullDenomResponse>(&QueryRequest::Custom(KujiraQuery::Denom(
+                DenomQuery::FullDenom {
+                    creator_addr: env.contract.address.clone(),
+                    subdenom: subdenom.clone(),
+                },
+            )))?
+            .denom;
+
+        Ok(InterfaceInstantiateResponse {
+            attributes: vec![],
+            messages: vec![DenomMsg::Create { subdenom }.into()],
+            factory_denom,
+        })
+    }
+
+    fn creation_fee(deps: Deps<KujiraQuery>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        deps.querier
+            .query::<QueryDenomParamsResponse>(&QueryRequest::Stargate {
+                path: QUERY_DENOM_PARAMS_PATH.to_string(),
+                data: Default::default(),
+            })?
+            .params
+            .creation_fee
+            .wrap_ok()
+    }
+
+    fn burn(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        _info: &MessageInfo,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        vec![DenomMsg::Burn {
+            denom: amount.denom.clone(),
+            amount: amount.amount,
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn mint(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        _info: &MessageInfo,
+        to: &Addr,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        vec![DenomMsg::Mint {
+            denom: amount.denom.clone(),
+            amount: amount.amount,
+            recipient: to.clone(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    /// The `Kujira` `denom` module requires an admin, so it can't be renounced
+    fn change_admin(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        let address = new_admin.ok_or(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "admin renounce".to_string(),
+        })?;
+
+        vec![DenomMsg::ChangeAdmin {
+            denom: denom.to_string(),
+            address: address.clone(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    /// The `Kujira` `denom` module has no force transfer
+    fn force_transfer(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        _from: &Addr,
+        _to: &Addr,
+        _coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        Err(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "force transfer".to_string(),
+        })
+    }
+
+    /// The `Kujira` `denom` module has no before send hook
+    fn set_before_send_hook(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        _denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        Ok(vec![])
+    }
+
+    /// The `Kujira` `denom` module doesn't expose bank metadata
+    fn set_metadata(
+        _deps: DepsMut<KujiraQuery>,
+        _env: &Env,
+        _denom: &str,
+        _metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<KujiraMsg>>> {
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,52 @@
+use bindings::{KujiraMsg, KujiraQuery};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, StdResult};
+use cw20_factory_base::contract::Cw20FactoryBase;
+use cw20_factory_pkg::cw20_factory::{
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    ContractResponse,
+};
+use interface::KujiraTokenFactoryInterface;
+
+pub mod bindings;
+pub mod interface;
+
+pub type Cw20FactoryKujira = Cw20FactoryBase<KujiraQuery, KujiraTokenFactoryInterface, KujiraMsg>;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut<KujiraQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResponse<KujiraMsg> {
+    Cw20FactoryKujira::instantiate(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut<KujiraQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResponse<KujiraMsg> {
+    Cw20FactoryKujira::execute(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn query(deps: Deps<KujiraQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    Cw20FactoryKujira::query(deps, env, msg)
+}
+
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut<KujiraQuery>,
+    env: Env,
+    msg: MigrateMsg,
+) -> ContractResponse<KujiraMsg> {
+    Cw20FactoryKujira::migrate(deps, env, msg)
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut<KujiraQuery>, env: Env, reply: Reply) -> ContractResponse<KujiraMsg> {
+    Cw20FactoryKujira::reply(deps, env, reply)
+}
@@ -0,0 +1,91 @@
+//! Subset of the `neutron-sdk` bindings used by the contract (`tokenfactory` only).
+//!
+//! Variants and fields mirror `neutron-sdk`, so the json is the same one expected by the chain.
+
+use cosmwasm_schema::{
+    cw_serde,
+    serde::{Deserialize, Serialize},
+};
+use cosmwasm_std::{Coin, CosmosMsg, CustomMsg, CustomQuery, DenomUnit, Uint128};
+
+/// `Neutron` `tokenfactory` is a fork of the `Osmosis` one and keeps its query path
+pub const QUERY_PARAMS_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/Params";
+
+#[cw_serde]
+pub enum NeutronMsg {
+    CreateDenom {
+        subdenom: String,
+    },
+    /// `new_admin_address` must be a valid address, the admin can't be renounced
+    ChangeAdmin {
+        denom: String,
+        new_admin_address: String,
+    },
+    MintTokens {
+        denom: String,
+        amount: Uint128,
+        mint_to_address: String,
+    },
+    /// An empty `burn_from_address` burns from the sender
+    BurnTokens {
+        denom: String,
+        amount: Uint128,
+        burn_from_address: String,
+    },
+    SetBeforeSendHook {
+        denom: String,
+        contract_addr: String,
+    },
+    ForceTransfer {
+        denom: String,
+        amount: Uint128,
+        transfer_from_address: String,
+        transfer_to_address: String,
+    },
+    SetDenomMetadata {
+        description: String,
+        denom_units: Vec<DenomUnit>,
+        base: String,
+        display: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        uri_hash: String,
+    },
+}
+
+impl CustomMsg for NeutronMsg {}
+
+impl From<NeutronMsg> for CosmosMsg<NeutronMsg> {
+    fn from(msg: NeutronMsg) -> Self {
+        CosmosMsg::Custom(msg)
+    }
+}
+
+#[cw_serde]
+pub enum NeutronQuery {
+    FullDenom {
+        creator_addr: String,
+        subdenom: String,
+    },
+}
+
+impl CustomQuery for NeutronQuery {}
+
+#[cw_serde]
+pub struct FullDenomResponse {
+    pub denom: String,
+}
+
+/// `Params` carries other fields not needed by the contract, so unknown fields are allowed
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct QueryParamsResponse {
+    pub params: Params,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+pub struct Params {
+    pub denom_creation_fee: Vec<Coin>,
+}
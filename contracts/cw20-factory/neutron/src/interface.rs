@@ -0,0 +1,158 @@
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Deps, DepsMut, Env, MessageInfo, QueryRequest};
+use cw20_factory_pkg::cw20_factory::{
+    interface::{
+        validate_tokenfactory_subdenom, InterfaceInstantiateResponse, NativeMetadata,
+        TokenFactoryInterface,
+    },
+    ContractResult, Cw20FactoryError,
+};
+use rhaki_cw_plus::traits::Wrapper;
+
+use crate::bindings::{
+    FullDenomResponse, NeutronMsg, NeutronQuery, QueryParamsResponse, QUERY_PARAMS_PATH,
+};
+
+/// Max length of a subdenom on the `Neutron` `tokenfactory`
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+pub struct NeutronTokenFactoryInterface {}
+
+impl TokenFactoryInterface<NeutronQuery, NeutronMsg> for NeutronTokenFactoryInterface {
+    fn instantiate(
+        deps: DepsMut<NeutronQuery>,
+        env: &Env,
+        _info: MessageInfo,
+        symbol: String,
+        subdenom: Option<String>,
+    ) -> ContractResult<InterfaceInstantiateResponse<NeutronMsg>> {
+        let subdenom = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_tokenfactory_subdenom(&subdenom, MAX_SUBDENOM_LENGTH)?;
+
+        let factory_denom = deps
+            .querier
+            .query::<FullDenomResponse>(&QueryRequest::Custom(NeutronQuery::FullDenom {
+                creator_addr: env.contract.address.to_string(),
+                subdenom: subdenom.clone(),
+            }))?
+            .denom;
+
+        Ok(InterfaceInstantiateResponse {
+            attributes: vec![],
+            messages: vec![NeutronMsg::CreateDenom { subdenom }.into()],
+            factory_denom,
+        })
+    }
+
+    fn creation_fee(deps: Deps<NeutronQuery>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        deps.querier
+            .query::<QueryParamsResponse>(&QueryRequest::Stargate {
+                path: QUERY_PARAMS_PATH.to_string(),
+                data: Default::default(),
+            })?
+            .params
+            .denom_creation_fee
+            .wrap_ok()
+    }
+
+    fn burn(
+        _deps: DepsMut<NeutronQuery>,
+        _env: &Env,
+        _info: &MessageInfo,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        vec![NeutronMsg::BurnTokens {
+            denom: amount.denom.clone(),
+            amount: amount.amount,
+            burn_from_address: "".to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn mint(
+        _deps: DepsMut<NeutronQuery>,
+        _env: &Env,
+        _info: &MessageInfo,
+        to: &Addr,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        vec![NeutronMsg::MintTokens {
+            denom: amount.denom.clone(),
+            amount: amount.amount,
+            mint_to_address: to.to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    /// `ChangeAdmin` requires a valid `new_admin_address`, so the admin can't be renounced
+    fn change_admin(
+        _deps: DepsMut<NeutronQuery>,
+        _env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        let new_admin = new_admin.ok_or(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "admin renounce".to_string(),
+        })?;
+
+        vec![NeutronMsg::ChangeAdmin {
+            denom: denom.to_string(),
+            new_admin_address: new_admin.to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn force_transfer(
+        _deps: DepsMut<NeutronQuery>,
+        _env: &Env,
+        from: &Addr,
+        to: &Addr,
+        coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        vec![NeutronMsg::ForceTransfer {
+            denom: coin.denom.clone(),
+            amount: coin.amount,
+            transfer_from_address: from.to_string(),
+            transfer_to_address: to.to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn set_before_send_hook(
+        _deps: DepsMut<NeutronQuery>,
+        env: &Env,
+        denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        vec![NeutronMsg::SetBeforeSendHook {
+            denom: denom.to_string(),
+            contract_addr: env.contract.address.to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+
+    fn set_metadata(
+        _deps: DepsMut<NeutronQuery>,
+        _env: &Env,
+        denom: &str,
+        metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<NeutronMsg>>> {
+        let (denom_units, display) = metadata.denom_units(denom);
+
+        vec![NeutronMsg::SetDenomMetadata {
+            description: metadata.description.clone().unwrap_or_default(),
+            denom_units,
+            base: denom.to_string(),
+            display,
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone().unwrap_or_default(),
+            uri_hash: "".to_string(),
+        }
+        .into()]
+        .wrap_ok()
+    }
+}
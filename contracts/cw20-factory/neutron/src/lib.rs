@@ -0,0 +1,58 @@
+use bindings::{NeutronMsg, NeutronQuery};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, StdResult};
+use cw20_factory_base::contract::Cw20FactoryBase;
+use cw20_factory_pkg::cw20_factory::{
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
+    ContractResponse,
+};
+use interface::NeutronTokenFactoryInterface;
+
+pub mod bindings;
+pub mod interface;
+
+pub type Cw20FactoryNeutron =
+    Cw20FactoryBase<NeutronQuery, NeutronTokenFactoryInterface, NeutronMsg>;
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResponse<NeutronMsg> {
+    Cw20FactoryNeutron::instantiate(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResponse<NeutronMsg> {
+    Cw20FactoryNeutron::execute(deps, env, info, msg)
+}
+
+#[entry_point]
+pub fn query(deps: Deps<NeutronQuery>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    Cw20FactoryNeutron::query(deps, env, msg)
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut<NeutronQuery>, env: Env, msg: SudoMsg) -> ContractResponse<NeutronMsg> {
+    Cw20FactoryNeutron::sudo(deps, env, msg)
+}
+
+#[entry_point]
+pub fn migrate(
+    deps: DepsMut<NeutronQuery>,
+    env: Env,
+    msg: MigrateMsg,
+) -> ContractResponse<NeutronMsg> {
+    Cw20FactoryNeutron::migrate(deps, env, msg)
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut<NeutronQuery>, env: Env, reply: Reply) -> ContractResponse<NeutronMsg> {
+    Cw20FactoryNeutron::reply(deps, env, reply)
+}
@@ -1,14 +1,28 @@
-use cosmwasm_std::{Addr, Coin, CosmosMsg, DepsMut, Empty, Env, MessageInfo};
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Uint128};
 use cw20_factory_pkg::cw20_factory::{
-    interface::{InterfaceInstantiateResponse, TokenFactoryInterface},
-    ContractResult,
+    interface::{
+        validate_tokenfactory_subdenom, InterfaceInstantiateResponse, NativeMetadata,
+        TokenFactoryInterface,
+    },
+    ContractResult, Cw20FactoryError,
 };
 use osmosis_std::types::{
-    cosmos::base::v1beta1::Coin as CosmosCoin,
-    osmosis::tokenfactory::v1beta1::{MsgBurn, MsgCreateDenom, MsgMint},
+    cosmos::{
+        bank::v1beta1::{DenomUnit, Metadata},
+        base::v1beta1::Coin as CosmosCoin,
+    },
+    osmosis::tokenfactory::v1beta1::{
+        MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgForceTransfer, MsgMint, MsgSetBeforeSendHook,
+        MsgSetDenomMetadata, TokenfactoryQuerier,
+    },
 };
 use rhaki_cw_plus::traits::Wrapper;
 
+/// Max length of a subdenom on the `Osmosis` `tokenfactory`
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
 pub struct OsmosisTokenFactoryInterface {}
 
 impl TokenFactoryInterface for OsmosisTokenFactoryInterface {
@@ -17,8 +31,10 @@ impl TokenFactoryInterface for OsmosisTokenFactoryInterface {
         env: &Env,
         _info: MessageInfo,
         symbol: String,
+        subdenom: Option<String>,
     ) -> ContractResult<InterfaceInstantiateResponse<Empty>> {
-        let subdenom = symbol.to_lowercase();
+        let subdenom = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_tokenfactory_subdenom(&subdenom, MAX_SUBDENOM_LENGTH)?;
         let msg = MsgCreateDenom {
             sender: env.contract.address.to_string(),
             subdenom: subdenom.clone(),
@@ -37,6 +53,22 @@ impl TokenFactoryInterface for OsmosisTokenFactoryInterface {
         })
     }
 
+    fn creation_fee(deps: Deps<Empty>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        TokenfactoryQuerier::new(&deps.querier)
+            .params()?
+            .params
+            .map(|params| params.denom_creation_fee)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|coin| {
+                Ok(Coin::new(
+                    Uint128::from_str(&coin.amount)?.u128(),
+                    coin.denom,
+                ))
+            })
+            .collect()
+    }
+
     fn burn(
         _deps: DepsMut<Empty>,
         env: &Env,
@@ -77,12 +109,120 @@ impl TokenFactoryInterface for OsmosisTokenFactoryInterface {
         }]
         .wrap_ok()
     }
+
+    /// `MsgChangeAdmin` requires a valid `new_admin`, so the admin can't be renounced
+    fn change_admin(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let new_admin = new_admin.ok_or(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "admin renounce".to_string(),
+        })?;
+
+        let msg = MsgChangeAdmin {
+            sender: env.contract.address.to_string(),
+            denom: denom.to_string(),
+            new_admin: new_admin.to_string(),
+        }
+        .to_any();
+
+        vec![CosmosMsg::Stargate {
+            type_url: MsgChangeAdmin::TYPE_URL.to_string(),
+            value: msg.value.into(),
+        }]
+        .wrap_ok()
+    }
+
+    fn force_transfer(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        from: &Addr,
+        to: &Addr,
+        coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let msg = MsgForceTransfer {
+            sender: env.contract.address.to_string(),
+            amount: Some(coin.clone().to_cosmos_coin()),
+            transfer_from_address: from.to_string(),
+            transfer_to_address: to.to_string(),
+        }
+        .to_any();
+
+        vec![CosmosMsg::Stargate {
+            type_url: MsgForceTransfer::TYPE_URL.to_string(),
+            value: msg.value.into(),
+        }]
+        .wrap_ok()
+    }
+
+    fn set_before_send_hook(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let msg = MsgSetBeforeSendHook {
+            sender: env.contract.address.to_string(),
+            denom: denom.to_string(),
+            cosmwasm_address: env.contract.address.to_string(),
+        }
+        .to_any();
+
+        vec![CosmosMsg::Stargate {
+            type_url: MsgSetBeforeSendHook::TYPE_URL.to_string(),
+            value: msg.value.into(),
+        }]
+        .wrap_ok()
+    }
+
+    fn set_metadata(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let msg = MsgSetDenomMetadata {
+            sender: env.contract.address.to_string(),
+            metadata: bank_metadata(denom, metadata).wrap_some(),
+        }
+        .to_any();
+
+        vec![CosmosMsg::Stargate {
+            type_url: MsgSetDenomMetadata::TYPE_URL.to_string(),
+            value: msg.value.into(),
+        }]
+        .wrap_ok()
+    }
 }
 
 fn denom(contract: &Addr, subdenom: String) -> String {
     format!("factory/{}/{}", contract, subdenom)
 }
 
+/// Build the bank `Metadata`
+fn bank_metadata(denom: &str, metadata: &NativeMetadata) -> Metadata {
+    let (denom_units, display) = metadata.denom_units(denom);
+
+    Metadata {
+        description: metadata.description.clone().unwrap_or_default(),
+        denom_units: denom_units
+            .into_iter()
+            .map(|unit| DenomUnit {
+                denom: unit.denom,
+                exponent: unit.exponent,
+                aliases: unit.aliases,
+            })
+            .collect(),
+        base: denom.to_string(),
+        display,
+        name: metadata.name.clone(),
+        symbol: metadata.symbol.clone(),
+        uri: metadata.uri.clone().unwrap_or_default(),
+        uri_hash: "".to_string(),
+    }
+}
+
 trait IntoCosmosCoin {
     fn to_cosmos_coin(self) -> CosmosCoin;
 }
@@ -1,7 +1,7 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, StdResult};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Reply, StdResult};
 use cw20_factory_base::contract::Cw20FactoryBase;
 use cw20_factory_pkg::cw20_factory::{
-    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
+    msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, SudoMsg},
     ContractResponse,
 };
 use interface::OsmosisTokenFactoryInterface;
@@ -35,7 +35,17 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     Cw20FactoryOsmosis::query(deps, env, msg)
 }
 
+#[entry_point]
+pub fn sudo(deps: DepsMut, env: Env, msg: SudoMsg) -> ContractResponse<Empty> {
+    Cw20FactoryOsmosis::sudo(deps, env, msg)
+}
+
 #[entry_point]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> ContractResponse<Empty> {
     Cw20FactoryOsmosis::migrate(deps, env, msg)
 }
+
+#[entry_point]
+pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> ContractResponse<Empty> {
+    Cw20FactoryOsmosis::reply(deps, env, reply)
+}
@@ -0,0 +1,146 @@
+use cosmwasm_std::{Addr, BankMsg, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo};
+use cw20_factory_pkg::cw20_factory::{
+    interface::{
+        validate_tokenfactory_subdenom, InterfaceInstantiateResponse, NativeMetadata,
+        TokenFactoryInterface,
+    },
+    ContractResult, Cw20FactoryError,
+};
+use rhaki_cw_plus::traits::Wrapper;
+
+use crate::proto::{
+    Metadata, MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata, TypeUrl,
+};
+
+/// Max length of a subdenom on the `Sei` `tokenfactory`
+pub const MAX_SUBDENOM_LENGTH: usize = 44;
+
+pub struct SeiTokenFactoryInterface {}
+
+impl TokenFactoryInterface for SeiTokenFactoryInterface {
+    fn instantiate(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: MessageInfo,
+        symbol: String,
+        subdenom: Option<String>,
+    ) -> ContractResult<InterfaceInstantiateResponse<Empty>> {
+        let subdenom = subdenom.unwrap_or_else(|| symbol.to_lowercase());
+        validate_tokenfactory_subdenom(&subdenom, MAX_SUBDENOM_LENGTH)?;
+
+        let msg = MsgCreateDenom {
+            sender: env.contract.address.to_string(),
+            subdenom: subdenom.clone(),
+        }
+        .into_stargate();
+
+        Ok(InterfaceInstantiateResponse {
+            attributes: vec![],
+            messages: vec![msg],
+            factory_denom: denom(&env.contract.address, subdenom),
+        })
+    }
+
+    /// The `Sei` `tokenfactory` charges the denom creation as gas, no fee has to be attached
+    fn creation_fee(_deps: Deps<Empty>, _env: &Env) -> ContractResult<Vec<Coin>> {
+        Ok(vec![])
+    }
+
+    /// `MsgBurn` always burns from the sender, which is the contract itself
+    fn burn(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: &MessageInfo,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        vec![MsgBurn {
+            sender: env.contract.address.to_string(),
+            amount: Some(amount.clone().into()),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+
+    /// `MsgMint` always mints to the sender, so the minted amount is sent to `to` afterwards
+    fn mint(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        _info: &MessageInfo,
+        to: &Addr,
+        amount: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let mint_msg = MsgMint {
+            sender: env.contract.address.to_string(),
+            amount: Some(amount.clone().into()),
+        }
+        .into_stargate();
+
+        let send_msg = BankMsg::Send {
+            to_address: to.to_string(),
+            amount: vec![amount.clone()],
+        }
+        .into();
+
+        vec![mint_msg, send_msg].wrap_ok()
+    }
+
+    /// `MsgChangeAdmin` requires a valid `new_admin`, so the admin can't be renounced
+    fn change_admin(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        let new_admin = new_admin.ok_or(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "admin renounce".to_string(),
+        })?;
+
+        vec![MsgChangeAdmin {
+            sender: env.contract.address.to_string(),
+            denom: denom.to_string(),
+            new_admin: new_admin.to_string(),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+
+    /// The `Sei` `tokenfactory` has no force transfer
+    fn force_transfer(
+        _deps: DepsMut<Empty>,
+        _env: &Env,
+        _from: &Addr,
+        _to: &Addr,
+        _coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        Err(Cw20FactoryError::NativeOperationNotSupported {
+            operation: "force transfer".to_string(),
+        })
+    }
+
+    /// The `Sei` `tokenfactory` has no before send hook
+    fn set_before_send_hook(
+        _deps: DepsMut<Empty>,
+        _env: &Env,
+        _denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        Ok(vec![])
+    }
+
+    fn set_metadata(
+        _deps: DepsMut<Empty>,
+        env: &Env,
+        denom: &str,
+        metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<Empty>>> {
+        vec![MsgSetDenomMetadata {
+            sender: env.contract.address.to_string(),
+            metadata: Metadata::new(denom, metadata).wrap_some(),
+        }
+        .into_stargate()]
+        .wrap_ok()
+    }
+}
+
+fn denom(contract: &Addr, subdenom: String) -> String {
+    format!("factory/{}/{}", contract, subdenom)
+}
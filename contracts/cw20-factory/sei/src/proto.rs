@@ -0,0 +1,71 @@
+//! `seiprotocol.seichain.tokenfactory` messages used by the contract.
+//!
+//! Only the fields the contract sets are declared, unknown fields are ignored by the chain.
+
+use prost::Message;
+
+pub use cw20_factory_pkg::cw20_factory::proto::{Coin, DenomUnit, Metadata, TypeUrl};
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+}
+
+impl TypeUrl for MsgCreateDenom {
+    const TYPE_URL: &'static str = "/seiprotocol.seichain.tokenfactory.MsgCreateDenom";
+}
+
+/// Mint `amount` to `sender`
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgMint {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+}
+
+impl TypeUrl for MsgMint {
+    const TYPE_URL: &'static str = "/seiprotocol.seichain.tokenfactory.MsgMint";
+}
+
+/// Burn `amount` from `sender`
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgBurn {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+}
+
+impl TypeUrl for MsgBurn {
+    const TYPE_URL: &'static str = "/seiprotocol.seichain.tokenfactory.MsgBurn";
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub denom: String,
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+impl TypeUrl for MsgChangeAdmin {
+    const TYPE_URL: &'static str = "/seiprotocol.seichain.tokenfactory.MsgChangeAdmin";
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct MsgSetDenomMetadata {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: Option<Metadata>,
+}
+
+impl TypeUrl for MsgSetDenomMetadata {
+    const TYPE_URL: &'static str = "/seiprotocol.seichain.tokenfactory.MsgSetDenomMetadata";
+}
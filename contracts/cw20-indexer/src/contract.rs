@@ -1,5 +1,8 @@
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
 use cw20_factory_pkg::cw20_indexer::{
+    definitions::DenomScheme,
     error::{ContractResponse, Cw20IndexerError},
     msgs::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
 };
@@ -9,27 +12,36 @@ use rhaki_cw_plus::{
 };
 
 use crate::{
-    function::validate_denom,
-    query::{qy_cw20_denom, qy_cw20_token_info, qy_tokens_info},
-    state::CW20_MAP,
+    function::{cache_token, is_indexer_admin, validate_denom},
+    query::{qy_cw20_denom, qy_cw20_token_info, qy_denoms_by_cw20, qy_token_info, qy_tokens_info},
+    state::{CW20_MAP, DENOM_BY_CW20, DENOM_SCHEMES, REGISTERED_SCHEMES, TOKEN_CACHE},
 };
 
 #[entry_point]
 pub fn instantiate(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
     _info: MessageInfo,
-    _msg: InstantiateMsg,
+    msg: InstantiateMsg,
 ) -> ContractResponse {
+    let denom_schemes = msg.denom_schemes.unwrap_or_else(DenomScheme::defaults);
+
+    if denom_schemes.is_empty() {
+        return Err(Cw20IndexerError::EmptyDenomSchemes {});
+    }
+
+    DENOM_SCHEMES.save(deps.storage, &denom_schemes)?;
+
     Response::new().wrap_ok()
 }
 
 #[entry_point]
-pub fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) -> ContractResponse {
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> ContractResponse {
     match msg {
         ExecuteMsg::RegisterDenom(msg) => {
-            validate_denom(&msg.denom, &info.sender)?;
-            qy_cw20_token_info(deps.as_ref(), &info.sender)?;
+            let scheme =
+                validate_denom(&DENOM_SCHEMES.load(deps.storage)?, &msg.denom, &info.sender)?;
+            let token_info = qy_cw20_token_info(deps.as_ref(), &info.sender)?;
             qy_cw20_denom(deps.as_ref(), &info.sender).map_err(|_| {
                 Cw20IndexerError::DenomNotFound {
                     cw20: info.sender.clone(),
@@ -39,9 +51,48 @@ pub fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: ExecuteMsg) ->
                 return Err(Cw20IndexerError::DenomAlredySaved { denom: msg.denom });
             };
 
-            CW20_MAP.save(deps.storage, msg.denom, &info.sender)?;
+            if let Some(denom) = DENOM_BY_CW20.may_load(deps.storage, info.sender.clone())? {
+                return Err(Cw20IndexerError::Cw20AlreadyRegistered {
+                    cw20: info.sender,
+                    denom,
+                });
+            }
+
+            CW20_MAP.save(deps.storage, msg.denom.clone(), &info.sender)?;
+            DENOM_BY_CW20.save(deps.storage, info.sender.clone(), &msg.denom)?;
+            REGISTERED_SCHEMES.save(deps.storage, msg.denom.clone(), &scheme)?;
+            cache_token(deps, &env, msg.denom, &info.sender, token_info, true)?;
             Response::new().wrap_ok()
         }
+        ExecuteMsg::RefreshDenom { denom } => {
+            let cw20_addr = CW20_MAP.better_load(deps.storage, denom.clone())?;
+            let token_info = qy_cw20_token_info(deps.as_ref(), &cw20_addr)?;
+            cache_token(deps, &env, denom.clone(), &cw20_addr, token_info, false)?;
+            Response::new()
+                .add_attribute("action", "refresh_denom")
+                .add_attribute("denom", denom)
+                .wrap_ok()
+        }
+        ExecuteMsg::DeregisterDenom { denom } => {
+            let response = Response::new()
+                .add_attribute("action", "deregister_denom")
+                .add_attribute("denom", &denom);
+
+            let Some(cw20_addr) = CW20_MAP.may_load(deps.storage, denom.clone())? else {
+                return response.add_attribute("registered", "false").wrap_ok();
+            };
+
+            if info.sender != cw20_addr && !is_indexer_admin(deps.as_ref(), &env, &info.sender)? {
+                return Err(Cw20IndexerError::Unauthorized { denom });
+            }
+
+            CW20_MAP.remove(deps.storage, denom.clone());
+            DENOM_BY_CW20.remove(deps.storage, cw20_addr.clone());
+            REGISTERED_SCHEMES.remove(deps.storage, denom.clone());
+            TOKEN_CACHE.remove(deps.storage, denom);
+
+            response.add_attribute("cw20", cw20_addr).wrap_ok()
+        }
     }
 }
 
@@ -52,15 +103,46 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             let cw20_addr = CW20_MAP.better_load(deps.storage, denom)?;
             qy_cw20_token_info(deps, &cw20_addr).into_binary()
         }
+        QueryMsg::TokenDetails { denom } => {
+            let cw20_addr = CW20_MAP.better_load(deps.storage, denom.clone())?;
+            qy_token_info(deps, denom, cw20_addr.wrap_some()).into_binary()
+        }
         QueryMsg::TokensInfo {
             start_after,
             limit,
             order,
-        } => qy_tokens_info(deps, start_after, limit, order).into_binary(),
+            mode,
+        } => qy_tokens_info(deps, start_after, limit, order, mode).into_binary(),
+        QueryMsg::TokenInfoByCw20 { cw20_addr } => {
+            let cw20_addr = deps.api.addr_validate(&cw20_addr)?;
+            let denom = DENOM_BY_CW20.better_load(deps.storage, cw20_addr.clone())?;
+            qy_token_info(deps, denom, cw20_addr.wrap_some()).into_binary()
+        }
+        QueryMsg::DenomsByCw20 {
+            start_after,
+            limit,
+            order,
+        } => qy_denoms_by_cw20(deps, start_after, limit, order).into_binary(),
     }
 }
 
 #[entry_point]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResponse {
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> ContractResponse {
+    // Indexers instantiated before the denom schemes were introduced
+    if !DENOM_SCHEMES.exists(deps.storage) {
+        DENOM_SCHEMES.save(deps.storage, &DenomScheme::defaults())?;
+    }
+
+    // Indexers instantiated before the reverse lookup was introduced
+    let registered = CW20_MAP
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (denom, cw20_addr) in registered {
+        if !DENOM_BY_CW20.has(deps.storage, cw20_addr.clone()) {
+            DENOM_BY_CW20.save(deps.storage, cw20_addr, &denom)?;
+        }
+    }
+
     Response::new().wrap_ok()
 }
@@ -1,23 +1,124 @@
-use cosmwasm_std::Addr;
-use cw20_factory_pkg::cw20_indexer::error::{ContractResult, Cw20IndexerError};
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, StdResult};
+use cw20::TokenInfoResponse;
+use cw20_factory_pkg::{
+    cw20_factory::helpers::Cw20FactoryQuerier,
+    cw20_indexer::{
+        definitions::DenomScheme,
+        error::{ContractResult, Cw20IndexerError},
+    },
+};
 
-pub fn validate_denom(denom: &str, sender: &Addr) -> ContractResult<()> {
-    let split: Vec<&str> = denom.split('/').collect();
+use crate::{
+    scheme::validator,
+    state::{CachedToken, TOKEN_CACHE},
+};
 
-    if split.len() != 3 {
-        return Err(Cw20IndexerError::InvalidDenomFormatLenght {
-            denom: denom.to_string(),
-        });
-    }
+/// Validate a denom registered by `sender` against the `schemes`, tried in order.
+///
+/// Return the first matched scheme
+pub fn validate_denom(
+    schemes: &[DenomScheme],
+    denom: &str,
+    sender: &Addr,
+) -> ContractResult<DenomScheme> {
+    let mut errors = vec![];
 
-    let first = split[0];
-    let second = split[1];
+    for scheme in schemes {
+        match validator(scheme).validate(denom, sender) {
+            Ok(()) => return Ok(scheme.clone()),
+            Err(err) => errors.push(err),
+        }
+    }
 
-    if second != sender.as_str() || first != "factory" {
-        return Err(Cw20IndexerError::InvalidDenomFormatData {
-            denom: denom.to_string(),
-        });
+    if errors.len() == 1 {
+        return Err(errors.remove(0));
     }
 
-    Ok(())
+    Err(Cw20IndexerError::DenomSchemeNotMatched {
+        denom: denom.to_string(),
+        reasons: errors
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect::<Vec<_>>()
+            .join("; "),
+    })
+}
+
+/// `true` if `sender` is the wasm admin of the indexer
+pub fn is_indexer_admin(deps: Deps, env: &Env, sender: &Addr) -> StdResult<bool> {
+    let admin = deps
+        .querier
+        .query_wasm_contract_info(&env.contract.address)?
+        .admin;
+
+    Ok(admin.as_deref() == Some(sender.as_str()))
+}
+
+/// Cache `token_info` and the current supply of `cw20_addr` under `denom`.
+///
+/// `registered_at` is setted only when `registration` is `true`, and kept on refresh
+pub fn cache_token(
+    deps: DepsMut,
+    env: &Env,
+    denom: String,
+    cw20_addr: &Addr,
+    token_info: TokenInfoResponse,
+    registration: bool,
+) -> StdResult<()> {
+    let supply = deps.querier.query_supply_details(cw20_addr)?;
+
+    let registered_at = if registration {
+        Some(env.block.height)
+    } else {
+        TOKEN_CACHE
+            .may_load(deps.storage, denom.clone())?
+            .and_then(|cached| cached.registered_at)
+    };
+
+    TOKEN_CACHE.save(
+        deps.storage,
+        denom,
+        &CachedToken {
+            name: token_info.name,
+            symbol: token_info.symbol,
+            decimals: token_info.decimals,
+            total_supply: supply.total_supply,
+            cw20_supply: supply.cw20_supply,
+            native_supply: supply.native_supply,
+            registered_at,
+            updated_at: env.block.height,
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Addr;
+    use cw20_factory_pkg::cw20_indexer::{definitions::DenomScheme, error::Cw20IndexerError};
+
+    use super::validate_denom;
+
+    #[test]
+    fn t_1_first_matched_scheme() {
+        let sender = Addr::unchecked("cosmos1cw20");
+        let schemes = DenomScheme::defaults();
+
+        assert_eq!(
+            validate_denom(&schemes, "factory/cosmos1cw20/foo", &sender).unwrap(),
+            DenomScheme::Tokenfactory
+        );
+        assert_eq!(
+            validate_denom(&schemes, "foo-cosmos1cw20", &sender).unwrap(),
+            DenomScheme::Coreum
+        );
+
+        assert!(matches!(
+            validate_denom(&schemes, "ibc/foo", &sender).unwrap_err(),
+            Cw20IndexerError::DenomSchemeNotMatched { .. }
+        ));
+        assert!(matches!(
+            validate_denom(&[DenomScheme::Coreum], "ibc/foo", &sender).unwrap_err(),
+            Cw20IndexerError::InvalidDenomFormatLenght { .. }
+        ));
+    }
 }
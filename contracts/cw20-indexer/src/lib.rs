@@ -1,4 +1,5 @@
 pub mod contract;
 mod function;
 mod query;
+mod scheme;
 mod state;
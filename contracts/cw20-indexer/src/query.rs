@@ -1,22 +1,22 @@
 use cosmwasm_std::{Addr, Deps, Order, StdResult};
 use cw20::TokenInfoResponse;
 use cw20_factory_pkg::{
-    cw20_factory::msgs::SupplyDetailsResponse, cw20_indexer::msgs::TokenDetails,
+    cw20_factory::helpers::{Cw20FactoryContract, Cw20FactoryQuerier},
+    cw20_indexer::{
+        definitions::{DenomScheme, QueryMode},
+        msgs::{DenomByCw20Response, TokenDetails},
+    },
 };
 use rhaki_cw_plus::{storage::StorageOrder, traits::Wrapper};
 
-use crate::state::CW20_MAP;
+use crate::state::{CW20_MAP, DENOM_BY_CW20, REGISTERED_SCHEMES, TOKEN_CACHE};
 
 pub fn qy_cw20_token_info(deps: Deps, cw20_addr: &Addr) -> StdResult<TokenInfoResponse> {
-    deps.querier
-        .query_wasm_smart(cw20_addr, &cw20::Cw20QueryMsg::TokenInfo {})
+    Cw20FactoryContract(cw20_addr.clone()).token_info(&deps.querier)
 }
 
 pub fn qy_cw20_denom(deps: Deps, cw20_addr: &Addr) -> StdResult<String> {
-    deps.querier.query_wasm_smart(
-        cw20_addr,
-        &cw20_factory_pkg::cw20_factory::msgs::QueryMsg::FactoryDenom {},
-    )
+    deps.querier.query_factory_denom(cw20_addr)
 }
 
 pub fn qy_tokens_info(
@@ -24,14 +24,40 @@ pub fn qy_tokens_info(
     start_after: Option<String>,
     limit: Option<u32>,
     order: Option<StorageOrder>,
+    mode: Option<QueryMode>,
 ) -> StdResult<Vec<TokenDetails>> {
     let order: Order = order.unwrap_or(StorageOrder::Descending).into();
+    let mode = mode.unwrap_or_default();
     rhaki_cw_plus::storage::map::get_items(deps.storage, &CW20_MAP, order, limit, start_after)?
         .into_iter()
-        .map(|(k, v)| qy_token_info(deps, k, v.wrap_some()))
+        .map(|(k, v)| match mode {
+            QueryMode::Live => qy_token_info(deps, k, v.wrap_some()),
+            QueryMode::Cached => qy_cached_token_info(deps, k, v),
+        })
         .collect()
 }
 
+pub fn qy_denoms_by_cw20(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    order: Option<StorageOrder>,
+) -> StdResult<Vec<DenomByCw20Response>> {
+    let order: Order = order.unwrap_or(StorageOrder::Ascending).into();
+    let start_after = start_after
+        .map(|cw20_addr| deps.api.addr_validate(&cw20_addr))
+        .transpose()?;
+
+    rhaki_cw_plus::storage::map::get_items(deps.storage, &DENOM_BY_CW20, order, limit, start_after)?
+        .into_iter()
+        .map(|(cw20_addr, denom)| DenomByCw20Response {
+            cw20_addr: cw20_addr.to_string(),
+            denom,
+        })
+        .collect::<Vec<_>>()
+        .wrap_ok()
+}
+
 pub fn qy_token_info(
     deps: Deps,
     denom: String,
@@ -42,10 +68,10 @@ pub fn qy_token_info(
         .unwrap_or_else(|| CW20_MAP.load(deps.storage, denom.clone()))?;
     let info = qy_cw20_token_info(deps, &cw20_addr)?;
 
-    let supply: SupplyDetailsResponse = deps.querier.query_wasm_smart(
-        &cw20_addr,
-        &cw20_factory_pkg::cw20_factory::msgs::QueryMsg::SupplyDetails {},
-    )?;
+    let supply = deps.querier.query_supply_details(&cw20_addr)?;
+
+    let scheme = qy_registered_scheme(deps, &denom)?;
+    let cached = TOKEN_CACHE.may_load(deps.storage, denom.clone())?;
 
     TokenDetails {
         cw20_addr: cw20_addr.to_string(),
@@ -56,6 +82,27 @@ pub fn qy_token_info(
         total_supply: supply.total_supply,
         cw20_supply: supply.cw20_supply,
         native_supply: supply.native_supply,
+        scheme,
+        registered_at: cached.as_ref().and_then(|cached| cached.registered_at),
+        updated_at: cached.map(|cached| cached.updated_at),
     }
     .wrap_ok()
 }
+
+/// `TokenDetails` from storage only, falling back on `qy_token_info` if `denom` has never been cached
+pub fn qy_cached_token_info(deps: Deps, denom: String, cw20_addr: Addr) -> StdResult<TokenDetails> {
+    match TOKEN_CACHE.may_load(deps.storage, denom.clone())? {
+        Some(cached) => {
+            let scheme = qy_registered_scheme(deps, &denom)?;
+            cached.into_details(&cw20_addr, denom, scheme).wrap_ok()
+        }
+        None => qy_token_info(deps, denom, cw20_addr.wrap_some()),
+    }
+}
+
+fn qy_registered_scheme(deps: Deps, denom: &str) -> StdResult<DenomScheme> {
+    REGISTERED_SCHEMES
+        .may_load(deps.storage, denom.to_string())?
+        .unwrap_or(DenomScheme::Tokenfactory)
+        .wrap_ok()
+}
@@ -0,0 +1,249 @@
+use cosmwasm_std::Addr;
+use cw20_factory_pkg::cw20_indexer::{
+    definitions::DenomScheme,
+    error::{ContractResult, Cw20IndexerError},
+};
+
+/// Max length of a subdenom on the `Kujira` `denom` module
+const KUJIRA_MAX_SUBDENOM_LENGTH: usize = 44;
+
+/// Validate the denoms registered by a `cw20` against a `DenomScheme`
+pub trait DenomValidator {
+    /// Format of the accepted denoms, reported on errors
+    fn format(&self) -> String;
+
+    fn validate(&self, denom: &str, sender: &Addr) -> ContractResult<()>;
+
+    fn invalid_lenght(&self, denom: &str) -> Cw20IndexerError {
+        Cw20IndexerError::InvalidDenomFormatLenght {
+            denom: denom.to_string(),
+            format: self.format(),
+        }
+    }
+
+    fn invalid_data(&self, denom: &str) -> Cw20IndexerError {
+        Cw20IndexerError::InvalidDenomFormatData {
+            denom: denom.to_string(),
+            format: self.format(),
+        }
+    }
+}
+
+pub fn validator(scheme: &DenomScheme) -> Box<dyn DenomValidator + '_> {
+    match scheme {
+        DenomScheme::Tokenfactory => Box::new(TokenfactoryValidator {}),
+        DenomScheme::Coreum => Box::new(CoreumValidator {}),
+        DenomScheme::Prefixed { prefix } => Box::new(PrefixedValidator { prefix }),
+        DenomScheme::Kujira => Box::new(KujiraValidator {}),
+    }
+}
+
+pub struct TokenfactoryValidator {}
+
+impl DenomValidator for TokenfactoryValidator {
+    fn format(&self) -> String {
+        "factory/contract/subdenom".to_string()
+    }
+
+    fn validate(&self, denom: &str, sender: &Addr) -> ContractResult<()> {
+        let split: Vec<&str> = denom.split('/').collect();
+
+        if split.len() != 3 {
+            return Err(self.invalid_lenght(denom));
+        }
+
+        if split[0] != "factory" || split[1] != sender.as_str() {
+            return Err(self.invalid_data(denom));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct CoreumValidator {}
+
+impl DenomValidator for CoreumValidator {
+    fn format(&self) -> String {
+        "subunit-contract".to_string()
+    }
+
+    fn validate(&self, denom: &str, sender: &Addr) -> ContractResult<()> {
+        let Some((subunit, issuer)) = denom.split_once('-') else {
+            return Err(self.invalid_lenght(denom));
+        };
+
+        if subunit.is_empty() || issuer != sender.as_str() {
+            return Err(self.invalid_data(denom));
+        }
+
+        Ok(())
+    }
+}
+
+pub struct PrefixedValidator<'a> {
+    pub prefix: &'a str,
+}
+
+impl DenomValidator for PrefixedValidator<'_> {
+    fn format(&self) -> String {
+        format!("{}contract", self.prefix)
+    }
+
+    fn validate(&self, denom: &str, sender: &Addr) -> ContractResult<()> {
+        match denom.strip_prefix(self.prefix) {
+            Some(rest) if rest == sender.as_str() => Ok(()),
+            _ => Err(self.invalid_data(denom)),
+        }
+    }
+}
+
+/// Same shape of `TokenfactoryValidator`, with the `subdenom` rules of the `denom` module
+pub struct KujiraValidator {}
+
+impl DenomValidator for KujiraValidator {
+    fn format(&self) -> String {
+        "factory/contract/subdenom".to_string()
+    }
+
+    fn validate(&self, denom: &str, sender: &Addr) -> ContractResult<()> {
+        TokenfactoryValidator {}.validate(denom, sender)?;
+
+        let subdenom = denom.split('/').nth(2).unwrap_or_default();
+
+        if subdenom.is_empty() || subdenom.len() > KUJIRA_MAX_SUBDENOM_LENGTH {
+            return Err(self.invalid_data(denom));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::Addr;
+    use cw20_factory_pkg::cw20_indexer::{definitions::DenomScheme, error::Cw20IndexerError};
+
+    use super::validator;
+
+    fn sender() -> Addr {
+        Addr::unchecked("cosmos1cw20")
+    }
+
+    fn lenght(denom: &str, format: &str) -> Cw20IndexerError {
+        Cw20IndexerError::InvalidDenomFormatLenght {
+            denom: denom.to_string(),
+            format: format.to_string(),
+        }
+    }
+
+    fn data(denom: &str, format: &str) -> Cw20IndexerError {
+        Cw20IndexerError::InvalidDenomFormatData {
+            denom: denom.to_string(),
+            format: format.to_string(),
+        }
+    }
+
+    #[test]
+    fn t_1_tokenfactory() {
+        let validator = validator(&DenomScheme::Tokenfactory);
+        let format = "factory/contract/subdenom";
+
+        validator
+            .validate("factory/cosmos1cw20/foo", &sender())
+            .unwrap();
+
+        assert_eq!(
+            validator
+                .validate("factory/cosmos1cw20/foo/bar", &sender())
+                .unwrap_err(),
+            lenght("factory/cosmos1cw20/foo/bar", format)
+        );
+        assert_eq!(
+            validator
+                .validate("factory/cosmos1other/foo", &sender())
+                .unwrap_err(),
+            data("factory/cosmos1other/foo", format)
+        );
+        assert_eq!(
+            validator
+                .validate("ibc/cosmos1cw20/foo", &sender())
+                .unwrap_err(),
+            data("ibc/cosmos1cw20/foo", format)
+        );
+    }
+
+    #[test]
+    fn t_2_coreum() {
+        let validator = validator(&DenomScheme::Coreum);
+        let format = "subunit-contract";
+
+        validator.validate("foo-cosmos1cw20", &sender()).unwrap();
+
+        assert_eq!(
+            validator.validate("foo", &sender()).unwrap_err(),
+            lenght("foo", format)
+        );
+        assert_eq!(
+            validator.validate("-cosmos1cw20", &sender()).unwrap_err(),
+            data("-cosmos1cw20", format)
+        );
+        assert_eq!(
+            validator
+                .validate("foo-cosmos1other", &sender())
+                .unwrap_err(),
+            data("foo-cosmos1other", format)
+        );
+    }
+
+    #[test]
+    fn t_3_prefixed() {
+        let scheme = DenomScheme::Prefixed {
+            prefix: "peggy".to_string(),
+        };
+        let validator = validator(&scheme);
+        let format = "peggycontract";
+
+        validator.validate("peggycosmos1cw20", &sender()).unwrap();
+
+        assert_eq!(
+            validator
+                .validate("peggycosmos1other", &sender())
+                .unwrap_err(),
+            data("peggycosmos1other", format)
+        );
+        assert_eq!(
+            validator.validate("cosmos1cw20", &sender()).unwrap_err(),
+            data("cosmos1cw20", format)
+        );
+    }
+
+    #[test]
+    fn t_4_kujira() {
+        let validator = validator(&DenomScheme::Kujira);
+        let format = "factory/contract/subdenom";
+
+        validator
+            .validate("factory/cosmos1cw20/foo", &sender())
+            .unwrap();
+
+        assert_eq!(
+            validator
+                .validate("factory/cosmos1cw20", &sender())
+                .unwrap_err(),
+            lenght("factory/cosmos1cw20", format)
+        );
+        assert_eq!(
+            validator
+                .validate("factory/cosmos1cw20/", &sender())
+                .unwrap_err(),
+            data("factory/cosmos1cw20/", format)
+        );
+
+        let long = format!("factory/cosmos1cw20/{}", "a".repeat(45));
+
+        assert_eq!(
+            validator.validate(&long, &sender()).unwrap_err(),
+            data(&long, format)
+        );
+    }
+}
@@ -1,4 +1,52 @@
-use cosmwasm_std::Addr;
-use cw_storage_plus::Map;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw20_factory_pkg::cw20_indexer::{definitions::DenomScheme, msgs::TokenDetails};
+use cw_storage_plus::{Item, Map};
 
 pub const CW20_MAP: Map<String, Addr> = Map::new("cw20_map");
+
+/// Reverse of `CW20_MAP`, kept in sync on registration
+pub const DENOM_BY_CW20: Map<Addr, String> = Map::new("denom_by_cw20");
+
+pub const DENOM_SCHEMES: Item<Vec<DenomScheme>> = Item::new("denom_schemes");
+
+/// Scheme matched on registration. Missing for the denoms registered before the schemes were introduced
+pub const REGISTERED_SCHEMES: Map<String, DenomScheme> = Map::new("registered_schemes");
+
+/// Details served by `QueryMode::Cached`, saved on registration and on `ExecuteMsg::RefreshDenom`
+pub const TOKEN_CACHE: Map<String, CachedToken> = Map::new("token_cache");
+
+#[cw_serde]
+pub struct CachedToken {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub total_supply: Uint128,
+    pub cw20_supply: Uint128,
+    pub native_supply: Uint128,
+    pub registered_at: Option<u64>,
+    pub updated_at: u64,
+}
+
+impl CachedToken {
+    pub fn into_details(
+        self,
+        cw20_addr: &Addr,
+        denom: String,
+        scheme: DenomScheme,
+    ) -> TokenDetails {
+        TokenDetails {
+            cw20_addr: cw20_addr.to_string(),
+            native_denom: denom,
+            name: self.name,
+            symbol: self.symbol,
+            decimals: self.decimals,
+            total_supply: self.total_supply,
+            cw20_supply: self.cw20_supply,
+            native_supply: self.native_supply,
+            scheme,
+            registered_at: self.registered_at,
+            updated_at: Some(self.updated_at),
+        }
+    }
+}
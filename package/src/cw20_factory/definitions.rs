@@ -1,8 +1,79 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_std::{Addr, Binary, Uint128};
 
+/// Native supply drift flagged by `ExecuteMsg::Reconcile`
+#[cw_serde]
+pub struct SupplyDrift {
+    pub tracked_supply: Uint128,
+    pub bank_supply: Uint128,
+    /// Block height of the reconciliation
+    pub height: u64,
+}
+
+/// Change of a supply cap in `ExecuteMsg::UpdateSupplyCaps`
+#[cw_serde]
+pub enum SupplyCapUpdate {
+    Set(Uint128),
+    Remove {},
+}
+
+/// `recipient` and `hook` are mutually exclusive.
+/// If none of them is provided, the sender receives the transmuted tokens
 #[cw_serde]
 pub enum TransmuteIntoMsg {
-    Cw20 {},
-    Native { amount: Uint128 },
+    /// Burn the `native` sent as funds and mint `cw20`.
+    /// With `hook`, the `cw20` are delivered with `Send` semantics, triggering `Receive` on `hook.contract`
+    Cw20 {
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    },
+    /// Burn `amount` `cw20` and mint `native`.
+    /// With `hook`, the `native` are attached as funds to the execution of `hook.msg` on `hook.contract`
+    Native {
+        amount: Uint128,
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    },
+}
+
+#[cw_serde]
+pub struct TransmuteHook {
+    pub contract: String,
+    pub msg: Binary,
+}
+
+/// Role allowed to execute `ExecuteMsg::CreateNative`
+#[cw_serde]
+#[derive(Default)]
+pub enum CreateNativeAuthority {
+    /// The `cw20` minter
+    Minter,
+    /// The `cw20` marketing address
+    Marketing,
+    /// The wasm admin of the contract
+    #[default]
+    ContractAdmin,
+}
+
+/// Role allowed to execute `ExecuteMsg::ForceTransfer`
+#[cw_serde]
+#[derive(Default)]
+pub enum ClawbackAdmin {
+    /// Never configured, can still be set on migration
+    #[default]
+    NotSet,
+    Enabled(Addr),
+    /// Permanently disabled, can't be set anymore
+    Disabled,
+}
+
+/// Admin of the `native` token on the `tokenfactory` module
+#[cw_serde]
+pub enum NativeAdmin {
+    /// The contract is the admin, `native` mint and burn are allowed
+    Contract,
+    /// The admin has been transferred, `native` mint and burn are not allowed anymore
+    Address(Addr),
+    /// The admin has been renounced, `native` supply can't be modified anymore
+    Renounced,
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Int256, Response, StdError};
+use cosmwasm_std::{Coin, Int256, Response, StdError, Uint128};
 use cw20_base::ContractError as Cw20BaseError;
 use thiserror::Error;
 
@@ -24,4 +24,46 @@ pub enum Cw20FactoryError {
 
     #[error("Native token alredy created")]
     NativeTokenAlredyCreated {},
+
+    #[error("Insufficient creation fee: required {required}, received {received}")]
+    InsufficientCreationFee { required: Coin, received: Coin },
+
+    #[error("Invalid subdenom {subdenom}: {reason}")]
+    InvalidSubdenom { subdenom: String, reason: String },
+
+    #[error("Native admin not owned: current admin is {admin}")]
+    NativeAdminNotOwned { admin: String },
+
+    #[error("Contract is paused")]
+    Paused {},
+
+    #[error("Address {address} is blocked")]
+    AddressBlocked { address: String },
+
+    #[error("Clawback is disabled")]
+    ClawbackDisabled {},
+
+    #[error("Only one between recipient and hook can be provided")]
+    RecipientAndHook {},
+
+    #[error("Native {operation} is not supported on this chain")]
+    NativeOperationNotSupported { operation: String },
+
+    #[error("Unauthorized: sender is not the {role}")]
+    Unauthorized { role: String },
+
+    #[error("Unsupported message: {msg}")]
+    UnsupportedMessage { msg: String },
+
+    #[error("Minting would exceed the native cap of {cap}")]
+    CannotExceedNativeCap { cap: Uint128 },
+
+    #[error("Minting would exceed the cw20 cap of {cap}")]
+    CannotExceedCw20Cap { cap: Uint128 },
+
+    #[error("Cannot register into more than {max} indexers")]
+    TooManyIndexers { max: u32 },
+
+    #[error("Unexpected reply id: {id}")]
+    UnexpectedReply { id: u64 },
 }
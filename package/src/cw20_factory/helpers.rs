@@ -0,0 +1,578 @@
+use cosmwasm_schema::{cw_serde, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, CustomQuery, QuerierWrapper, StdResult, Uint128,
+    WasmMsg,
+};
+use cw20::{
+    AllAccountsResponse, AllAllowancesResponse, AllSpenderAllowancesResponse, AllowanceResponse,
+    BalanceResponse, DownloadLogoResponse, Expiration, Logo, MarketingInfoResponse, MinterResponse,
+    TokenInfoResponse,
+};
+
+use super::{
+    definitions::{ClawbackAdmin, NativeAdmin, SupplyCapUpdate, TransmuteHook, TransmuteIntoMsg},
+    msgs::{
+        BlocklistResponse, ExecuteMsg, HealthResponse, IndexersResponse, QueryMsg,
+        SupplyDetailsResponse, SupplyReconciliationResponse,
+    },
+};
+
+/// Wrapper around the address of a `cw20-factory`, with typed queries and `CosmosMsg` builders.
+///
+/// Builders return `CosmosMsg<Empty>`, use `IntoCustom` on chains with custom messages
+#[cw_serde]
+pub struct Cw20FactoryContract(pub Addr);
+
+impl Cw20FactoryContract {
+    pub fn addr(&self) -> Addr {
+        self.0.clone()
+    }
+
+    pub fn call(&self, msg: ExecuteMsg) -> StdResult<CosmosMsg> {
+        self.call_with_funds(msg, vec![])
+    }
+
+    pub fn call_with_funds(&self, msg: ExecuteMsg, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr().into(),
+            msg: to_json_binary(&msg)?,
+            funds,
+        }
+        .into())
+    }
+
+    fn query<CQ: CustomQuery, R: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        msg: &QueryMsg,
+    ) -> StdResult<R> {
+        querier.query_wasm_smart(self.addr(), msg)
+    }
+}
+
+// Execute
+
+impl Cw20FactoryContract {
+    /// Transmute the `native` attached as funds into `cw20`
+    pub fn transmute_into_cw20(
+        &self,
+        native: Coin,
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    ) -> StdResult<CosmosMsg> {
+        self.call_with_funds(
+            ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Cw20 { recipient, hook }),
+            vec![native],
+        )
+    }
+
+    pub fn transmute_into_native(
+        &self,
+        amount: Uint128,
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Native {
+            amount,
+            recipient,
+            hook,
+        }))
+    }
+
+    pub fn transmute_from(
+        &self,
+        owner: impl Into<String>,
+        amount: Uint128,
+        recipient: impl Into<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::TransmuteFrom {
+            owner: owner.into(),
+            amount,
+            recipient: recipient.into(),
+        })
+    }
+
+    pub fn register_to_indexer(&self, indexer_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::RegisterToIndexer {
+            indexer_addr: indexer_addr.into(),
+        })
+    }
+
+    pub fn refresh_indexers(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::RefreshIndexers { start_after, limit })
+    }
+
+    pub fn unregister_from_indexer(&self, indexer_addr: impl Into<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UnregisterFromIndexer {
+            indexer_addr: indexer_addr.into(),
+        })
+    }
+
+    /// `funds` has to cover the creation fee of the chain
+    pub fn create_native(
+        &self,
+        subdenom: Option<String>,
+        funds: Vec<Coin>,
+    ) -> StdResult<CosmosMsg> {
+        self.call_with_funds(ExecuteMsg::CreateNative { subdenom }, funds)
+    }
+
+    pub fn update_native_admin(&self, new_admin: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateNativeAdmin { new_admin })
+    }
+
+    pub fn set_paused(&self, paused: bool) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::SetPaused { paused })
+    }
+
+    pub fn update_blocklist(&self, add: Vec<String>, remove: Vec<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateBlocklist { add, remove })
+    }
+
+    pub fn reconcile(&self, flag: bool) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Reconcile { flag })
+    }
+
+    pub fn update_supply_caps(
+        &self,
+        native_cap: Option<SupplyCapUpdate>,
+        cw20_cap: Option<SupplyCapUpdate>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateSupplyCaps {
+            native_cap,
+            cw20_cap,
+        })
+    }
+
+    pub fn force_transfer(
+        &self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        amount: Uint128,
+        as_native: Option<bool>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::ForceTransfer {
+            from: from.into(),
+            to: to.into(),
+            amount,
+            as_native,
+        })
+    }
+
+    pub fn update_clawback_admin(&self, new_admin: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateClawbackAdmin { new_admin })
+    }
+
+    pub fn transfer(&self, recipient: impl Into<String>, amount: Uint128) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Transfer {
+            recipient: recipient.into(),
+            amount,
+        })
+    }
+
+    /// Attach the `native` as funds to burn them, `amount` burns `cw20`
+    pub fn burn(&self, amount: Option<Uint128>, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        self.call_with_funds(ExecuteMsg::Burn { amount }, funds)
+    }
+
+    pub fn send(
+        &self,
+        contract: impl Into<String>,
+        amount: Uint128,
+        msg: Binary,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Send {
+            contract: contract.into(),
+            amount,
+            msg,
+        })
+    }
+
+    pub fn increase_allowance(
+        &self,
+        spender: impl Into<String>,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::IncreaseAllowance {
+            spender: spender.into(),
+            amount,
+            expires,
+        })
+    }
+
+    pub fn decrease_allowance(
+        &self,
+        spender: impl Into<String>,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::DecreaseAllowance {
+            spender: spender.into(),
+            amount,
+            expires,
+        })
+    }
+
+    pub fn transfer_from(
+        &self,
+        owner: impl Into<String>,
+        recipient: impl Into<String>,
+        amount: Uint128,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::TransferFrom {
+            owner: owner.into(),
+            recipient: recipient.into(),
+            amount,
+        })
+    }
+
+    pub fn send_from(
+        &self,
+        owner: impl Into<String>,
+        contract: impl Into<String>,
+        amount: Uint128,
+        msg: Binary,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::SendFrom {
+            owner: owner.into(),
+            contract: contract.into(),
+            amount,
+            msg,
+        })
+    }
+
+    pub fn burn_from(&self, owner: impl Into<String>, amount: Uint128) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::BurnFrom {
+            owner: owner.into(),
+            amount,
+        })
+    }
+
+    pub fn mint(
+        &self,
+        recipient: impl Into<String>,
+        amount: Uint128,
+        as_native: Option<bool>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::Mint {
+            recipient: recipient.into(),
+            amount,
+            as_native,
+        })
+    }
+
+    pub fn update_minter(&self, new_minter: Option<String>) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateMinter { new_minter })
+    }
+
+    pub fn update_marketing(
+        &self,
+        project: Option<String>,
+        description: Option<String>,
+        marketing: Option<String>,
+    ) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UpdateMarketing {
+            project,
+            description,
+            marketing,
+        })
+    }
+
+    pub fn upload_logo(&self, logo: Logo) -> StdResult<CosmosMsg> {
+        self.call(ExecuteMsg::UploadLogo(logo))
+    }
+}
+
+// Query
+
+impl Cw20FactoryContract {
+    pub fn factory_denom<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<String> {
+        self.query(querier, &QueryMsg::FactoryDenom {})
+    }
+
+    pub fn native_admin<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<NativeAdmin> {
+        self.query(querier, &QueryMsg::NativeAdmin {})
+    }
+
+    pub fn clawback_admin<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<ClawbackAdmin> {
+        self.query(querier, &QueryMsg::ClawbackAdmin {})
+    }
+
+    pub fn paused<CQ: CustomQuery>(&self, querier: &QuerierWrapper<CQ>) -> StdResult<bool> {
+        self.query(querier, &QueryMsg::Paused {})
+    }
+
+    pub fn blocklist<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<BlocklistResponse> {
+        self.query(querier, &QueryMsg::Blocklist { start_after, limit })
+    }
+
+    pub fn indexers<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<IndexersResponse> {
+        self.query(querier, &QueryMsg::Indexers { start_after, limit })
+    }
+
+    pub fn supply_details<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<SupplyDetailsResponse> {
+        self.query(querier, &QueryMsg::SupplyDetails {})
+    }
+
+    pub fn health<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<HealthResponse> {
+        self.query(querier, &QueryMsg::Health { start_after, limit })
+    }
+
+    pub fn supply_reconciliation<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<SupplyReconciliationResponse> {
+        self.query(querier, &QueryMsg::SupplyReconciliation {})
+    }
+
+    pub fn balance<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        address: impl Into<String>,
+    ) -> StdResult<Uint128> {
+        self.query::<_, BalanceResponse>(
+            querier,
+            &QueryMsg::Balance {
+                address: address.into(),
+            },
+        )
+        .map(|res| res.balance)
+    }
+
+    pub fn token_info<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<TokenInfoResponse> {
+        self.query(querier, &QueryMsg::TokenInfo {})
+    }
+
+    /// `None` if the minter has been removed
+    pub fn minter<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<Option<MinterResponse>> {
+        self.query(querier, &QueryMsg::Minter {})
+    }
+
+    pub fn allowance<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        owner: impl Into<String>,
+        spender: impl Into<String>,
+    ) -> StdResult<AllowanceResponse> {
+        self.query(
+            querier,
+            &QueryMsg::Allowance {
+                owner: owner.into(),
+                spender: spender.into(),
+            },
+        )
+    }
+
+    pub fn all_allowances<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        owner: impl Into<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllAllowancesResponse> {
+        self.query(
+            querier,
+            &QueryMsg::AllAllowances {
+                owner: owner.into(),
+                start_after,
+                limit,
+            },
+        )
+    }
+
+    pub fn all_spender_allowances<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        spender: impl Into<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllSpenderAllowancesResponse> {
+        self.query(
+            querier,
+            &QueryMsg::AllSpenderAllowances {
+                spender: spender.into(),
+                start_after,
+                limit,
+            },
+        )
+    }
+
+    pub fn all_accounts<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<AllAccountsResponse> {
+        self.query(querier, &QueryMsg::AllAccounts { start_after, limit })
+    }
+
+    pub fn marketing_info<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<MarketingInfoResponse> {
+        self.query(querier, &QueryMsg::MarketingInfo {})
+    }
+
+    pub fn download_logo<CQ: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<CQ>,
+    ) -> StdResult<DownloadLogoResponse> {
+        self.query(querier, &QueryMsg::DownloadLogo {})
+    }
+}
+
+/// `cw20-factory` queries on `QuerierWrapper`
+pub trait Cw20FactoryQuerier {
+    fn query_factory_denom(&self, contract: &Addr) -> StdResult<String>;
+
+    fn query_supply_details(&self, contract: &Addr) -> StdResult<SupplyDetailsResponse>;
+
+    fn query_native_admin(&self, contract: &Addr) -> StdResult<NativeAdmin>;
+
+    fn query_clawback_admin(&self, contract: &Addr) -> StdResult<ClawbackAdmin>;
+
+    fn query_paused(&self, contract: &Addr) -> StdResult<bool>;
+}
+
+impl<CQ: CustomQuery> Cw20FactoryQuerier for QuerierWrapper<'_, CQ> {
+    fn query_factory_denom(&self, contract: &Addr) -> StdResult<String> {
+        Cw20FactoryContract(contract.clone()).factory_denom(self)
+    }
+
+    fn query_supply_details(&self, contract: &Addr) -> StdResult<SupplyDetailsResponse> {
+        Cw20FactoryContract(contract.clone()).supply_details(self)
+    }
+
+    fn query_native_admin(&self, contract: &Addr) -> StdResult<NativeAdmin> {
+        Cw20FactoryContract(contract.clone()).native_admin(self)
+    }
+
+    fn query_clawback_admin(&self, contract: &Addr) -> StdResult<ClawbackAdmin> {
+        Cw20FactoryContract(contract.clone()).clawback_admin(self)
+    }
+
+    fn query_paused(&self, contract: &Addr) -> StdResult<bool> {
+        Cw20FactoryContract(contract.clone()).paused(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        from_json, testing::MockQuerier, to_json_binary, Addr, Coin, ContractResult, CosmosMsg,
+        Empty, QuerierWrapper, SystemResult, Uint128, WasmMsg, WasmQuery,
+    };
+
+    use super::{Cw20FactoryContract, Cw20FactoryQuerier};
+    use crate::cw20_factory::{
+        definitions::TransmuteIntoMsg,
+        msgs::{ExecuteMsg, QueryMsg, SupplyDetailsResponse},
+    };
+
+    #[test]
+    fn t_1_builders() {
+        let contract = Cw20FactoryContract(Addr::unchecked("token"));
+        let native = Coin::new(100, "factory/token/foo");
+
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) = contract
+            .transmute_into_cw20(native.clone(), None, None)
+            .unwrap()
+        else {
+            panic!("expected WasmMsg::Execute");
+        };
+
+        assert_eq!(contract_addr, "token");
+        assert_eq!(funds, vec![native]);
+        assert_eq!(
+            from_json::<ExecuteMsg>(msg).unwrap(),
+            ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Cw20 {
+                recipient: None,
+                hook: None
+            })
+        );
+    }
+
+    #[test]
+    fn t_2_queries() {
+        let mut querier = MockQuerier::<Empty>::new(&[]);
+
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("expected WasmQuery::Smart");
+            };
+
+            assert_eq!(contract_addr, "token");
+
+            let res = match from_json::<QueryMsg>(msg).unwrap() {
+                QueryMsg::FactoryDenom {} => to_json_binary("factory/token/foo"),
+                QueryMsg::SupplyDetails {} => to_json_binary(&SupplyDetailsResponse {
+                    total_supply: Uint128::new(30),
+                    cw20_supply: Uint128::new(10),
+                    native_supply: Uint128::new(20),
+                    native_cap: None,
+                    cw20_cap: None,
+                }),
+                _ => panic!("unexpected query"),
+            };
+
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let token = Addr::unchecked("token");
+
+        assert_eq!(
+            querier.query_factory_denom(&token).unwrap(),
+            "factory/token/foo"
+        );
+        assert_eq!(
+            Cw20FactoryContract(token)
+                .supply_details(&querier)
+                .unwrap()
+                .native_supply,
+            Uint128::new(20)
+        );
+    }
+}
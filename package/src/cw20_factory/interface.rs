@@ -1,8 +1,15 @@
 use cosmwasm_std::{
-    Addr, Attribute, Coin, CosmosMsg, CustomQuery, DepsMut, Empty, Env, MessageInfo,
+    Addr, Attribute, Coin, CosmosMsg, CustomQuery, DenomUnit, Deps, DepsMut, Empty, Env,
+    MessageInfo, Reply, Response,
 };
 
-use crate::cw20_factory::ContractResult;
+use crate::cw20_factory::{traits::map_msg, ContractResult, Cw20FactoryError};
+
+/// Reply id of the `native` force transfer submessage, handled by the base contract
+pub const FORCE_TRANSFER_REPLY_ID: u64 = u64::MAX;
+
+/// Reply id of the indexer refresh submessages, handled by the base contract
+pub const INDEXER_REFRESH_REPLY_ID: u64 = u64::MAX - 1;
 
 pub struct InterfaceInstantiateResponse<CM> {
     pub attributes: Vec<Attribute>,
@@ -10,14 +17,59 @@ pub struct InterfaceInstantiateResponse<CM> {
     pub factory_denom: String,
 }
 
+/// Metadata shared between the `cw20` and the `native` representation
+#[derive(Clone, Debug, PartialEq)]
+pub struct NativeMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    pub description: Option<String>,
+    /// Url of the logo, if any
+    pub uri: Option<String>,
+}
+
+impl NativeMetadata {
+    /// Bank units of `denom`, returned with the `display` one.
+    ///
+    /// The `display` unit is the lowercase `symbol` with `decimals` as exponent.
+    /// If `decimals` is zero, the only unit is the base `denom`
+    pub fn denom_units(&self, denom: &str) -> (Vec<DenomUnit>, String) {
+        let mut denom_units = vec![DenomUnit {
+            denom: denom.to_string(),
+            exponent: 0,
+            aliases: vec![],
+        }];
+
+        let display = if self.decimals > 0 {
+            let display = self.symbol.to_lowercase();
+            denom_units.push(DenomUnit {
+                denom: display.clone(),
+                exponent: self.decimals.into(),
+                aliases: vec![],
+            });
+            display
+        } else {
+            denom.to_string()
+        };
+
+        (denom_units, display)
+    }
+}
+
 pub trait TokenFactoryInterface<CQ: CustomQuery = Empty, CM = Empty> {
     fn instantiate(
         deps: DepsMut<CQ>,
         env: &Env,
         info: MessageInfo,
         symbol: String,
+        subdenom: Option<String>,
     ) -> ContractResult<InterfaceInstantiateResponse<CM>>;
 
+    /// Fee required by the `tokenfactory` module to create a new denom.
+    ///
+    /// The fee has to be attached by the caller on native creation, the excess is refunded
+    fn creation_fee(deps: Deps<CQ>, env: &Env) -> ContractResult<Vec<Coin>>;
+
     fn burn(
         deps: DepsMut<CQ>,
         env: &Env,
@@ -32,4 +84,82 @@ pub trait TokenFactoryInterface<CQ: CustomQuery = Empty, CM = Empty> {
         to: &Addr,
         amount: &Coin,
     ) -> ContractResult<Vec<CosmosMsg<CM>>>;
+
+    /// Transfer the `tokenfactory` admin of the `native` token.
+    /// If `new_admin` is `None`, the admin is renounced
+    fn change_admin(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        denom: &str,
+        new_admin: Option<&Addr>,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>>;
+
+    /// Move `coin` from `from` to `to` without `from` approval
+    fn force_transfer(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        from: &Addr,
+        to: &Addr,
+        coin: &Coin,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>>;
+
+    /// Register the contract as before send hook of the `native` token,
+    /// so the contract receives `SudoMsg::BlockBeforeSend` and `SudoMsg::TrackBeforeSend`
+    fn set_before_send_hook(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        denom: &str,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>>;
+
+    /// Set the bank metadata of the `native` token.
+    ///
+    /// Called on native creation and every time the `cw20` marketing info change
+    fn set_metadata(
+        deps: DepsMut<CQ>,
+        env: &Env,
+        denom: &str,
+        metadata: &NativeMetadata,
+    ) -> ContractResult<Vec<CosmosMsg<CM>>>;
+
+    /// Convert the messages emitted by `cw20-base` into `CosmosMsg<CM>`.
+    ///
+    /// Override to map the messages not handled by `traits::map_msg`, like `CosmosMsg::Custom`
+    fn map_msg(msg: CosmosMsg<Empty>) -> ContractResult<CosmosMsg<CM>> {
+        map_msg(msg)
+    }
+
+    /// Handle the reply of a submessage emitted by the interface.
+    ///
+    /// Replies with `FORCE_TRANSFER_REPLY_ID` and `INDEXER_REFRESH_REPLY_ID` are handled by the base contract,
+    /// every other reply fails unless overridden
+    fn reply(_deps: DepsMut<CQ>, _env: &Env, reply: Reply) -> ContractResult<Response<CM>> {
+        Err(Cw20FactoryError::UnexpectedReply { id: reply.id })
+    }
+}
+
+/// Validate a `subdenom` against the `tokenfactory` rules:
+/// - not empty and at most `max_length` characters;
+/// - only `[a-zA-Z0-9/:._-]` characters.
+pub fn validate_tokenfactory_subdenom(subdenom: &str, max_length: usize) -> ContractResult<()> {
+    let invalid = |reason: String| Cw20FactoryError::InvalidSubdenom {
+        subdenom: subdenom.to_string(),
+        reason,
+    };
+
+    if subdenom.is_empty() {
+        return Err(invalid("subdenom is empty".to_string()));
+    }
+
+    if subdenom.len() > max_length {
+        return Err(invalid(format!("length exceeds {max_length} characters")));
+    }
+
+    if let Some(char) = subdenom
+        .chars()
+        .find(|char| !(char.is_ascii_alphanumeric() || "/:._-".contains(*char)))
+    {
+        return Err(invalid(format!("invalid character '{char}'")));
+    }
+
+    Ok(())
 }
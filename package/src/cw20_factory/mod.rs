@@ -1,7 +1,11 @@
 pub mod definitions;
 mod error;
+pub mod helpers;
 pub mod interface;
 pub mod msgs;
+#[cfg(feature = "proto")]
+pub mod proto;
+pub mod receive;
 
 pub mod traits;
 pub use error::{ContractResponse, ContractResult, Cw20FactoryError};
@@ -11,6 +15,8 @@ mod test {
     use cosmwasm_schema::cw_serde;
     use cosmwasm_std::{from_json, to_json_binary};
 
+    use super::{interface::validate_tokenfactory_subdenom, Cw20FactoryError};
+
     #[cw_serde]
     pub struct Lesser {
         pub a: String,
@@ -34,4 +40,36 @@ mod test {
 
         from_json::<Lesser>(to_json_binary(&greater).unwrap()).unwrap_err();
     }
+
+    #[test]
+    fn t_2_validate_subdenom() {
+        validate_tokenfactory_subdenom("foo", 44).unwrap();
+        validate_tokenfactory_subdenom("Foo-Bar_1.2/3:4", 44).unwrap();
+        validate_tokenfactory_subdenom(&"a".repeat(44), 44).unwrap();
+
+        let invalid = |subdenom: &str, reason: &str| Cw20FactoryError::InvalidSubdenom {
+            subdenom: subdenom.to_string(),
+            reason: reason.to_string(),
+        };
+
+        assert_eq!(
+            validate_tokenfactory_subdenom("", 44).unwrap_err(),
+            invalid("", "subdenom is empty")
+        );
+
+        assert_eq!(
+            validate_tokenfactory_subdenom(&"a".repeat(45), 44).unwrap_err(),
+            invalid(&"a".repeat(45), "length exceeds 44 characters")
+        );
+
+        assert_eq!(
+            validate_tokenfactory_subdenom("foo bar", 44).unwrap_err(),
+            invalid("foo bar", "invalid character ' '")
+        );
+
+        assert_eq!(
+            validate_tokenfactory_subdenom("foo$", 44).unwrap_err(),
+            invalid("foo$", "invalid character '$'")
+        );
+    }
 }
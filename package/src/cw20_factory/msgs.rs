@@ -1,9 +1,12 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Binary, Coin, Uint128};
 use cw20::{Cw20Coin, Expiration, Logo, MinterResponse};
 use cw20_base::msg::InstantiateMarketingInfo;
 
-use super::definitions::TransmuteIntoMsg;
+use super::definitions::{
+    ClawbackAdmin, CreateNativeAuthority, NativeAdmin, SupplyCapUpdate, SupplyDrift, TransmuteHook,
+    TransmuteIntoMsg,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -14,6 +17,19 @@ pub struct InstantiateMsg {
     pub mint: Option<MinterResponse>,
     pub marketing: Option<InstantiateMarketingInfo>,
     pub init_native: Option<InitNativeDetails>,
+    /// Subdenom of the native token, used only if `init_native` is provided.
+    /// If not provided, the lowercase `symbol` is used
+    pub subdenom: Option<String>,
+    /// Role allowed to execute `ExecuteMsg::CreateNative`.
+    /// If not provided, `CreateNativeAuthority::ContractAdmin` is used
+    pub create_native_authority: Option<CreateNativeAuthority>,
+    /// Address allowed to execute `ExecuteMsg::ForceTransfer`.
+    /// If not provided, clawback is not enabled
+    pub clawback_admin: Option<String>,
+    /// Max supply that can exist as `native`, on top of the `mint` cap
+    pub native_cap: Option<Uint128>,
+    /// Max supply that can exist as `cw20`, on top of the `mint` cap
+    pub cw20_cap: Option<Uint128>,
 }
 
 impl From<InstantiateMsg> for cw20_base::msg::InstantiateMsg {
@@ -33,10 +49,69 @@ impl From<InstantiateMsg> for cw20_base::msg::InstantiateMsg {
 pub enum ExecuteMsg {
     /// Transmute `cw20` into `native` token or vice versa
     TransmuteInto(TransmuteIntoMsg),
-    /// Register this contract into an indexer
+    /// Only with "approval" extension. Burns `amount` `cw20` from `owner`
+    /// and mints the `native` token to `recipient`
+    /// if `env.sender` has sufficient pre-approval.
+    TransmuteFrom {
+        owner: String,
+        amount: Uint128,
+        recipient: String,
+    },
+    /// Register this contract into an indexer, up to 10 indexers.
+    /// Only the contract admin can execute it
     RegisterToIndexer { indexer_addr: String },
-    /// Create native token after a migration from cw20-base
-    CreateNative {},
+    /// Ask a page of the indexers this contract registered into to refresh its cached details.
+    /// Refreshes are already pushed on supply and marketing changes. Anyone can execute it
+    RefreshIndexers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Deregister this contract from an indexer.
+    /// Only the contract admin can execute it
+    UnregisterFromIndexer { indexer_addr: String },
+    /// Compare the `native` supply tracked by the contract with the bank supply,
+    /// reporting both as attributes.
+    /// With `flag`, a drift is saved and returned by `QueryMsg::SupplyReconciliation`, no drift clears it.
+    /// Only the contract admin can execute it
+    Reconcile { flag: bool },
+    /// Set or remove the `native` and `cw20` caps, `None` leaves the cap unchanged.
+    /// Lowering a cap below the current supply only blocks new mints and transmutes.
+    /// Only the contract admin can execute it
+    UpdateSupplyCaps {
+        native_cap: Option<SupplyCapUpdate>,
+        cw20_cap: Option<SupplyCapUpdate>,
+    },
+    /// Create native token after a migration from cw20-base.
+    /// Only the `CreateNativeAuthority` role can execute it.
+    /// If `subdenom` is not provided, the lowercase `symbol` is used
+    CreateNative { subdenom: Option<String> },
+    /// Transfer the admin of the `native` token on the `tokenfactory` module.
+    /// Setting `None` will renounce the admin forever, on the chains supporting it.
+    /// Only the contract admin can execute it
+    UpdateNativeAdmin { new_admin: Option<String> },
+    /// Pause or unpause all transfers, on both `cw20` and `native` side.
+    /// Only the contract admin can execute it
+    SetPaused { paused: bool },
+    /// Add or remove addresses from the blocklist.
+    /// Blocked addresses can't send or receive the token, on both `cw20` and `native` side.
+    /// Only the contract admin can execute it
+    UpdateBlocklist {
+        add: Vec<String>,
+        remove: Vec<String>,
+    },
+    /// Move tokens from `from` to `to` without `from` approval, bypassing pause and blocklist.
+    /// If `as_native` is `true`, the transfer happens on the `native` token.
+    /// Only the clawback admin can execute it
+    ForceTransfer {
+        from: String,
+        to: String,
+        amount: Uint128,
+        as_native: Option<bool>,
+    },
+    /// Transfer the clawback admin role.
+    /// Setting `None` will disable the clawback forever.
+    /// Only the clawback admin can execute it
+    UpdateClawbackAdmin { new_admin: Option<String> },
     // --- Base CW20 variants ---
     /// Transfer is a base message to move tokens to another account without triggering actions
     Transfer { recipient: String, amount: Uint128 },
@@ -114,9 +189,40 @@ pub enum QueryMsg {
     /// Returns the factory denom for this token
     #[returns(String)]
     FactoryDenom {},
+    /// Returns the admin of the native token
+    #[returns(NativeAdmin)]
+    NativeAdmin {},
+    /// Returns the clawback admin
+    #[returns(ClawbackAdmin)]
+    ClawbackAdmin {},
+    /// Returns if the contract is paused
+    #[returns(bool)]
+    Paused {},
+    /// Returns the blocked addresses. Supports pagination.
+    #[returns(BlocklistResponse)]
+    Blocklist {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the indexers this contract is registered into. Supports pagination.
+    #[returns(IndexersResponse)]
+    Indexers {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     /// Returns the total supply of the contract, sum of all token balances.
     #[returns(SupplyDetailsResponse)]
     SupplyDetails {},
+    /// Returns the supply figures of the contract, summing the `cw20` balances of the page.
+    /// Sum the pages until `last_address` is `None` to get all the balances.
+    #[returns(HealthResponse)]
+    Health {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Returns the tracked `native` supply compared with the bank supply
+    #[returns(SupplyReconciliationResponse)]
+    SupplyReconciliation {},
     /// Returns the current balance of the given address, 0 if unset.
     #[returns(cw20::BalanceResponse)]
     Balance { address: String },
@@ -167,13 +273,86 @@ pub enum QueryMsg {
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// Role allowed to execute `ExecuteMsg::CreateNative`.
+    /// If not provided, the current value is kept
+    pub create_native_authority: Option<CreateNativeAuthority>,
+    /// Address allowed to execute `ExecuteMsg::ForceTransfer`.
+    /// Ignored if the clawback has been disabled
+    pub clawback_admin: Option<String>,
+}
+
+/// Accepted as `msg` of a `ExecuteMsg::Send` to the token contract itself
+#[cw_serde]
+pub enum Cw20HookMsg {
+    /// Transmute the sent `cw20` into `native`, like `TransmuteIntoMsg::Native`
+    TransmuteIntoNative {
+        recipient: Option<String>,
+        hook: Option<TransmuteHook>,
+    },
+}
+
+/// Messages sent by the `tokenfactory` module to the before send hook of the `native` token
+#[cw_serde]
+pub enum SudoMsg {
+    /// Called before every send, an error reverts the send
+    BlockBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+    /// Called before every send, errors are ignored
+    TrackBeforeSend {
+        from: String,
+        to: String,
+        amount: Coin,
+    },
+}
+
+#[cw_serde]
+pub struct BlocklistResponse {
+    pub addresses: Vec<String>,
+}
+
+#[cw_serde]
+pub struct HealthResponse {
+    pub cw20_supply: Uint128,
+    /// Sum of the `cw20` balances in the page
+    pub balances_sum: Uint128,
+    /// Last address of the page, `None` once all the balances are summed
+    pub last_address: Option<String>,
+    /// `native` supply minted minus burned by the contract
+    pub tracked_native_supply: Uint128,
+    /// `native` minted by the contract, transmutes included
+    pub native_minted: Uint128,
+    /// `native` burned by the contract, transmutes included
+    pub native_burned: Uint128,
+    pub bank_native_supply: Uint128,
+    /// Mint cap minus the total supply, `None` without cap
+    pub cap_headroom: Option<Uint128>,
+}
+
+#[cw_serde]
+pub struct SupplyReconciliationResponse {
+    /// `native` supply minted by the contract minus the burned one
+    pub tracked_supply: Uint128,
+    pub bank_supply: Uint128,
+    /// Last drift flagged by `ExecuteMsg::Reconcile`
+    pub flagged: Option<SupplyDrift>,
+}
+
+#[cw_serde]
+pub struct IndexersResponse {
+    pub indexers: Vec<String>,
+}
 
 #[cw_serde]
 pub struct SupplyDetailsResponse {
     pub total_supply: Uint128,
     pub cw20_supply: Uint128,
     pub native_supply: Uint128,
+    pub native_cap: Option<Uint128>,
+    pub cw20_cap: Option<Uint128>,
 }
 
 #[cw_serde]
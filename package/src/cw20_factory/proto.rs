@@ -0,0 +1,94 @@
+//! Protobuf types shared by the interfaces emitting `Stargate` messages.
+//!
+//! Only the fields the contract sets are declared, unknown fields are ignored by the chain.
+
+use cosmwasm_std::{Coin as StdCoin, CosmosMsg, DenomUnit as StdDenomUnit};
+use prost::Message;
+
+use super::interface::NativeMetadata;
+
+pub trait TypeUrl: Message + Sized {
+    const TYPE_URL: &'static str;
+
+    fn into_stargate<T>(self) -> CosmosMsg<T> {
+        CosmosMsg::Stargate {
+            type_url: Self::TYPE_URL.to_string(),
+            value: self.encode_to_vec().into(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Coin {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(string, tag = "2")]
+    pub amount: String,
+}
+
+impl From<StdCoin> for Coin {
+    fn from(coin: StdCoin) -> Self {
+        Coin {
+            denom: coin.denom,
+            amount: coin.amount.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct DenomUnit {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+    #[prost(uint32, tag = "2")]
+    pub exponent: u32,
+    #[prost(string, repeated, tag = "3")]
+    pub aliases: Vec<String>,
+}
+
+impl From<StdDenomUnit> for DenomUnit {
+    fn from(unit: StdDenomUnit) -> Self {
+        DenomUnit {
+            denom: unit.denom,
+            exponent: unit.exponent,
+            aliases: unit.aliases,
+        }
+    }
+}
+
+/// `cosmos.bank.v1beta1.Metadata`
+#[derive(Clone, PartialEq, Message)]
+pub struct Metadata {
+    #[prost(string, tag = "1")]
+    pub description: String,
+    #[prost(message, repeated, tag = "2")]
+    pub denom_units: Vec<DenomUnit>,
+    #[prost(string, tag = "3")]
+    pub base: String,
+    #[prost(string, tag = "4")]
+    pub display: String,
+    #[prost(string, tag = "5")]
+    pub name: String,
+    #[prost(string, tag = "6")]
+    pub symbol: String,
+    #[prost(string, tag = "7")]
+    pub uri: String,
+    #[prost(string, tag = "8")]
+    pub uri_hash: String,
+}
+
+impl Metadata {
+    pub fn new(denom: &str, metadata: &NativeMetadata) -> Self {
+        let (denom_units, display) = metadata.denom_units(denom);
+
+        Metadata {
+            description: metadata.description.clone().unwrap_or_default(),
+            denom_units: denom_units.into_iter().map(Into::into).collect(),
+            base: denom.to_string(),
+            display,
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone().unwrap_or_default(),
+            uri_hash: "".to_string(),
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! Receive side helpers for contracts accepting a `cw20-factory` token in both representations:
+//! - `cw20`, through `ExecuteMsg::Receive(Cw20ReceiveMsg)`, where `info.sender` is the token;
+//! - `native`, as the only coin in `info.funds`.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, CustomQuery, MessageInfo, QuerierWrapper, StdError, StdResult, Uint128};
+use cw20::Cw20ReceiveMsg;
+use thiserror::Error;
+
+use crate::cw20_indexer::msgs::{QueryMsg as IndexerQueryMsg, TokenDetails};
+
+use super::helpers::Cw20FactoryContract;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReceiveError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("No token received")]
+    NoFunds {},
+
+    #[error("Only one coin can be received, got {count}")]
+    MultipleCoins { count: usize },
+
+    #[error("Native funds can't be sent along a cw20 Receive")]
+    FundsWithCw20 {},
+
+    #[error("Token mismatch: expected {expected}, received {received}")]
+    TokenMismatch { expected: String, received: String },
+}
+
+/// Representation in which the token has been received
+#[cw_serde]
+pub enum Representation {
+    Cw20,
+    Native,
+}
+
+/// Token received, normalized on the `cw20` address
+#[cw_serde]
+pub struct ReceivedToken {
+    pub cw20_addr: Addr,
+    pub amount: Uint128,
+    pub representation: Representation,
+}
+
+/// How the received token is verified
+#[cw_serde]
+pub enum TokenVerifier {
+    /// Accept only the `cw20-factory` at this address, in both representations
+    Token(Addr),
+    /// Accept any token registered on the indexer at this address
+    Indexer(Addr),
+}
+
+/// Resolve the token received by `info`.
+/// `receive` is the `Cw20ReceiveMsg` if the execution comes from a `cw20` `Send`, `None` otherwise
+pub fn resolve_received_token<CQ: CustomQuery>(
+    querier: &QuerierWrapper<CQ>,
+    info: &MessageInfo,
+    receive: Option<&Cw20ReceiveMsg>,
+    verifier: &TokenVerifier,
+) -> Result<ReceivedToken, ReceiveError> {
+    match receive {
+        Some(receive) => {
+            if !info.funds.is_empty() {
+                return Err(ReceiveError::FundsWithCw20 {});
+            }
+
+            verify_cw20(querier, &info.sender, verifier)?;
+
+            Ok(ReceivedToken {
+                cw20_addr: info.sender.clone(),
+                amount: receive.amount,
+                representation: Representation::Cw20,
+            })
+        }
+        None => {
+            let coin = match info.funds.as_slice() {
+                [] => return Err(ReceiveError::NoFunds {}),
+                [coin] => coin,
+                funds => return Err(ReceiveError::MultipleCoins { count: funds.len() }),
+            };
+
+            Ok(ReceivedToken {
+                cw20_addr: verify_native(querier, &coin.denom, verifier)?,
+                amount: coin.amount,
+                representation: Representation::Native,
+            })
+        }
+    }
+}
+
+fn verify_cw20<CQ: CustomQuery>(
+    querier: &QuerierWrapper<CQ>,
+    cw20_addr: &Addr,
+    verifier: &TokenVerifier,
+) -> Result<(), ReceiveError> {
+    let expected = match verifier {
+        TokenVerifier::Token(token) => token.clone(),
+        TokenVerifier::Indexer(indexer) => {
+            let denom = Cw20FactoryContract(cw20_addr.clone()).factory_denom(querier)?;
+            indexed_cw20(querier, indexer, denom)?
+        }
+    };
+
+    if expected != cw20_addr {
+        return Err(ReceiveError::TokenMismatch {
+            expected: expected.to_string(),
+            received: cw20_addr.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Return the `cw20` address of the `native` `denom`
+fn verify_native<CQ: CustomQuery>(
+    querier: &QuerierWrapper<CQ>,
+    denom: &str,
+    verifier: &TokenVerifier,
+) -> Result<Addr, ReceiveError> {
+    match verifier {
+        TokenVerifier::Token(token) => {
+            let expected = Cw20FactoryContract(token.clone()).factory_denom(querier)?;
+
+            if expected != denom {
+                return Err(ReceiveError::TokenMismatch {
+                    expected,
+                    received: denom.to_string(),
+                });
+            }
+
+            Ok(token.clone())
+        }
+        TokenVerifier::Indexer(indexer) => Ok(indexed_cw20(querier, indexer, denom.to_string())?),
+    }
+}
+
+fn indexed_cw20<CQ: CustomQuery>(
+    querier: &QuerierWrapper<CQ>,
+    indexer: &Addr,
+    denom: String,
+) -> StdResult<Addr> {
+    querier
+        .query_wasm_smart::<TokenDetails>(indexer, &IndexerQueryMsg::TokenDetails { denom })
+        .map(|details| Addr::unchecked(details.cw20_addr))
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{
+        from_json,
+        testing::{mock_info, MockQuerier},
+        to_json_binary, Addr, Binary, Coin, ContractResult, Empty, QuerierWrapper, StdError,
+        SystemError, SystemResult, Uint128, WasmQuery,
+    };
+    use cw20::Cw20ReceiveMsg;
+
+    use super::{
+        resolve_received_token, ReceiveError, ReceivedToken, Representation, TokenVerifier,
+    };
+    use crate::{
+        cw20_factory::msgs::QueryMsg,
+        cw20_indexer::{
+            definitions::DenomScheme,
+            msgs::{QueryMsg as IndexerQueryMsg, TokenDetails},
+        },
+    };
+
+    const TOKEN: &str = "token";
+    const OTHER: &str = "other";
+    const INDEXER: &str = "indexer";
+    const DENOM: &str = "factory/token/foo";
+    const OTHER_DENOM: &str = "factory/other/bar";
+
+    /// `TOKEN` and `OTHER` are factory tokens, only `TOKEN` is registered on `INDEXER`
+    fn querier() -> MockQuerier {
+        let mut querier = MockQuerier::<Empty>::new(&[]);
+
+        querier.update_wasm(|query| {
+            let WasmQuery::Smart { contract_addr, msg } = query else {
+                panic!("expected WasmQuery::Smart");
+            };
+
+            let res = match contract_addr.as_str() {
+                TOKEN => match from_json::<QueryMsg>(msg).unwrap() {
+                    QueryMsg::FactoryDenom {} => to_json_binary(DENOM),
+                    _ => panic!("unexpected query"),
+                },
+                OTHER => match from_json::<QueryMsg>(msg).unwrap() {
+                    QueryMsg::FactoryDenom {} => to_json_binary(OTHER_DENOM),
+                    _ => panic!("unexpected query"),
+                },
+                INDEXER => match from_json::<IndexerQueryMsg>(msg).unwrap() {
+                    IndexerQueryMsg::TokenDetails { denom } if denom == DENOM => {
+                        to_json_binary(&token_details())
+                    }
+                    IndexerQueryMsg::TokenDetails { denom } => {
+                        return SystemResult::Ok(ContractResult::Err(format!(
+                            "Denom not registered: {denom}"
+                        )))
+                    }
+                    _ => panic!("unexpected query"),
+                },
+                _ => {
+                    return SystemResult::Err(SystemError::NoSuchContract {
+                        addr: contract_addr.clone(),
+                    })
+                }
+            };
+
+            SystemResult::Ok(ContractResult::Ok(res.unwrap()))
+        });
+
+        querier
+    }
+
+    fn token_details() -> TokenDetails {
+        TokenDetails {
+            cw20_addr: TOKEN.to_string(),
+            native_denom: DENOM.to_string(),
+            name: "Token Foo".to_string(),
+            symbol: "FOO".to_string(),
+            decimals: 6,
+            total_supply: Uint128::new(100),
+            cw20_supply: Uint128::new(50),
+            native_supply: Uint128::new(50),
+            scheme: DenomScheme::Tokenfactory,
+            registered_at: Some(1),
+            updated_at: Some(1),
+        }
+    }
+
+    fn receive_msg(amount: u128) -> Cw20ReceiveMsg {
+        Cw20ReceiveMsg {
+            sender: "user".to_string(),
+            amount: Uint128::new(amount),
+            msg: Binary::default(),
+        }
+    }
+
+    fn received(amount: u128, representation: Representation) -> ReceivedToken {
+        ReceivedToken {
+            cw20_addr: Addr::unchecked(TOKEN),
+            amount: Uint128::new(amount),
+            representation,
+        }
+    }
+
+    #[test]
+    fn t_1_cw20() {
+        let querier = querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let receive = receive_msg(10);
+
+        for verifier in [
+            TokenVerifier::Token(Addr::unchecked(TOKEN)),
+            TokenVerifier::Indexer(Addr::unchecked(INDEXER)),
+        ] {
+            assert_eq!(
+                resolve_received_token(&querier, &mock_info(TOKEN, &[]), Some(&receive), &verifier)
+                    .unwrap(),
+                received(10, Representation::Cw20)
+            );
+
+            assert_eq!(
+                resolve_received_token(
+                    &querier,
+                    &mock_info(TOKEN, &[Coin::new(1, DENOM)]),
+                    Some(&receive),
+                    &verifier
+                )
+                .unwrap_err(),
+                ReceiveError::FundsWithCw20 {}
+            );
+        }
+    }
+
+    #[test]
+    fn t_2_cw20_mismatch() {
+        let querier = querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let receive = receive_msg(10);
+
+        assert_eq!(
+            resolve_received_token(
+                &querier,
+                &mock_info(OTHER, &[]),
+                Some(&receive),
+                &TokenVerifier::Token(Addr::unchecked(TOKEN))
+            )
+            .unwrap_err(),
+            ReceiveError::TokenMismatch {
+                expected: TOKEN.to_string(),
+                received: OTHER.to_string()
+            }
+        );
+
+        // Not registered on the indexer
+        assert!(matches!(
+            resolve_received_token(
+                &querier,
+                &mock_info(OTHER, &[]),
+                Some(&receive),
+                &TokenVerifier::Indexer(Addr::unchecked(INDEXER))
+            )
+            .unwrap_err(),
+            ReceiveError::Std(StdError::GenericErr { .. })
+        ));
+
+        // Not a cw20-factory
+        assert!(matches!(
+            resolve_received_token(
+                &querier,
+                &mock_info("plain_cw20", &[]),
+                Some(&receive),
+                &TokenVerifier::Indexer(Addr::unchecked(INDEXER))
+            )
+            .unwrap_err(),
+            ReceiveError::Std(StdError::GenericErr { .. })
+        ));
+    }
+
+    #[test]
+    fn t_3_native() {
+        let querier = querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+
+        for verifier in [
+            TokenVerifier::Token(Addr::unchecked(TOKEN)),
+            TokenVerifier::Indexer(Addr::unchecked(INDEXER)),
+        ] {
+            assert_eq!(
+                resolve_received_token(
+                    &querier,
+                    &mock_info("user", &[Coin::new(20, DENOM)]),
+                    None,
+                    &verifier
+                )
+                .unwrap(),
+                received(20, Representation::Native)
+            );
+        }
+    }
+
+    #[test]
+    fn t_4_native_mismatch() {
+        let querier = querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let verifier = TokenVerifier::Token(Addr::unchecked(TOKEN));
+
+        assert_eq!(
+            resolve_received_token(
+                &querier,
+                &mock_info("user", &[Coin::new(20, OTHER_DENOM)]),
+                None,
+                &verifier
+            )
+            .unwrap_err(),
+            ReceiveError::TokenMismatch {
+                expected: DENOM.to_string(),
+                received: OTHER_DENOM.to_string()
+            }
+        );
+
+        assert!(matches!(
+            resolve_received_token(
+                &querier,
+                &mock_info("user", &[Coin::new(20, OTHER_DENOM)]),
+                None,
+                &TokenVerifier::Indexer(Addr::unchecked(INDEXER))
+            )
+            .unwrap_err(),
+            ReceiveError::Std(StdError::GenericErr { .. })
+        ));
+    }
+
+    #[test]
+    fn t_5_native_funds() {
+        let querier = querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let verifier = TokenVerifier::Token(Addr::unchecked(TOKEN));
+
+        assert_eq!(
+            resolve_received_token(&querier, &mock_info("user", &[]), None, &verifier).unwrap_err(),
+            ReceiveError::NoFunds {}
+        );
+
+        assert_eq!(
+            resolve_received_token(
+                &querier,
+                &mock_info("user", &[Coin::new(20, DENOM), Coin::new(1, "uosmo")]),
+                None,
+                &verifier
+            )
+            .unwrap_err(),
+            ReceiveError::MultipleCoins { count: 2 }
+        );
+    }
+}
@@ -1,57 +1,184 @@
 use cosmwasm_std::{CosmosMsg, Empty, Response, SubMsg};
 
-pub trait IntoCustom<T> {
+use super::{ContractResult, Cw20FactoryError};
+
+/// Conversion of a single `CosmosMsg<Empty>`, used by `IntoCustom::to_custom_with`
+pub type MsgMapper<'a, T> = &'a dyn Fn(CosmosMsg<Empty>) -> ContractResult<CosmosMsg<T>>;
+
+pub trait IntoCustom<T>: Sized {
     type Output;
 
-    fn to_custom(self) -> Self::Output;
+    /// Convert every `CosmosMsg<Empty>` with `map`
+    fn to_custom_with(self, map: MsgMapper<T>) -> ContractResult<Self::Output>;
+
+    /// Convert every `CosmosMsg<Empty>` with `map_msg`
+    fn to_custom(self) -> ContractResult<Self::Output> {
+        self.to_custom_with(&map_msg)
+    }
+}
+
+/// Map the `CosmosMsg` variants that don't depend on the custom message.
+///
+/// Fails with `Cw20FactoryError::UnsupportedMessage` on the others, like `CosmosMsg::Custom`
+pub fn map_msg<T>(msg: CosmosMsg<Empty>) -> ContractResult<CosmosMsg<T>> {
+    match msg {
+        CosmosMsg::Bank(msg) => Ok(CosmosMsg::<T>::Bank(msg)),
+        CosmosMsg::Wasm(msg) => Ok(CosmosMsg::<T>::Wasm(msg)),
+        CosmosMsg::Staking(msg) => Ok(CosmosMsg::<T>::Staking(msg)),
+        CosmosMsg::Distribution(msg) => Ok(CosmosMsg::<T>::Distribution(msg)),
+        CosmosMsg::Stargate { type_url, value } => Ok(CosmosMsg::<T>::Stargate { type_url, value }),
+        CosmosMsg::Ibc(msg) => Ok(CosmosMsg::<T>::Ibc(msg)),
+        CosmosMsg::Gov(msg) => Ok(CosmosMsg::<T>::Gov(msg)),
+        msg => Err(Cw20FactoryError::UnsupportedMessage {
+            msg: format!("{msg:?}"),
+        }),
+    }
+}
+
+impl<T> IntoCustom<T> for CosmosMsg<Empty> {
+    type Output = CosmosMsg<T>;
+
+    fn to_custom_with(self, map: MsgMapper<T>) -> ContractResult<CosmosMsg<T>> {
+        map(self)
+    }
 }
 
 impl<T> IntoCustom<T> for Vec<CosmosMsg<Empty>> {
     type Output = Vec<CosmosMsg<T>>;
-    fn to_custom(self) -> Vec<CosmosMsg<T>> {
-        self.into_iter().map(|msg| msg.to_custom()).collect()
+
+    fn to_custom_with(self, map: MsgMapper<T>) -> ContractResult<Vec<CosmosMsg<T>>> {
+        self.into_iter().map(map).collect()
     }
 }
 
 impl<T> IntoCustom<T> for Vec<SubMsg<Empty>> {
     type Output = Vec<SubMsg<T>>;
-    fn to_custom(self) -> Vec<SubMsg<T>> {
+
+    fn to_custom_with(self, map: MsgMapper<T>) -> ContractResult<Vec<SubMsg<T>>> {
         self.into_iter()
-            .map(|msg| SubMsg {
-                id: msg.id,
-                msg: msg.msg.to_custom(),
-                gas_limit: msg.gas_limit,
-                reply_on: msg.reply_on,
+            .map(|msg| {
+                Ok(SubMsg {
+                    id: msg.id,
+                    msg: map(msg.msg)?,
+                    gas_limit: msg.gas_limit,
+                    reply_on: msg.reply_on,
+                })
             })
             .collect()
     }
 }
 
-impl<T> IntoCustom<T> for CosmosMsg<Empty> {
-    type Output = CosmosMsg<T>;
+impl<T> IntoCustom<T> for Response<Empty> {
+    type Output = Response<T>;
+
+    fn to_custom_with(self, map: MsgMapper<T>) -> ContractResult<Response<T>> {
+        let mut response = Response::<T>::new()
+            .add_submessages(self.messages.to_custom_with(map)?)
+            .add_attributes(self.attributes)
+            .add_events(self.events);
 
-    fn to_custom(self) -> CosmosMsg<T> {
-        match self {
-            CosmosMsg::Bank(msg) => CosmosMsg::<T>::Bank(msg),
-            CosmosMsg::Wasm(msg) => CosmosMsg::<T>::Wasm(msg),
-            CosmosMsg::Staking(msg) => CosmosMsg::<T>::Staking(msg),
-            CosmosMsg::Distribution(msg) => CosmosMsg::<T>::Distribution(msg),
-            CosmosMsg::Stargate { type_url, value } => CosmosMsg::<T>::Stargate { type_url, value },
-            CosmosMsg::Ibc(msg) => CosmosMsg::<T>::Ibc(msg),
-            CosmosMsg::Gov(msg) => CosmosMsg::<T>::Gov(msg),
-            _ => unimplemented!("Unsupported CosmosMsg type"),
+        if let Some(data) = self.data {
+            response = response.set_data(data);
         }
+
+        Ok(response)
     }
 }
 
-impl<T> IntoCustom<T> for Response<Empty> {
-    type Output = Response<T>;
+#[cfg(test)]
+mod test {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::{
+        BankMsg, Binary, Coin, CosmosMsg, CustomMsg, Empty, Event, Response, SubMsg,
+    };
 
-    fn to_custom(self) -> Self::Output {
-        Response::<T>::new()
-            .add_attributes(self.attributes)
-            .add_messages(self.messages.into_iter().map(|val| val.msg.to_custom()))
-            .add_events(self.events)
-            .set_data(self.data.unwrap_or_default())
+    use super::{map_msg, IntoCustom};
+    use crate::cw20_factory::{ContractResult, Cw20FactoryError};
+
+    #[cw_serde]
+    enum ChainMsg {
+        Noop {},
+    }
+
+    impl CustomMsg for ChainMsg {}
+
+    fn bank_msg() -> CosmosMsg<Empty> {
+        BankMsg::Burn {
+            amount: vec![Coin::new(1, "ucoin")],
+        }
+        .into()
+    }
+
+    #[test]
+    fn t_1_unsupported() {
+        assert_eq!(
+            bank_msg().to_custom().unwrap(),
+            CosmosMsg::<ChainMsg>::Bank(BankMsg::Burn {
+                amount: vec![Coin::new(1, "ucoin")]
+            })
+        );
+
+        let err = Response::<Empty>::new()
+            .add_message(bank_msg())
+            .add_message(CosmosMsg::Custom(Empty {}))
+            .to_custom()
+            .map(|_: Response<ChainMsg>| ())
+            .unwrap_err();
+
+        assert!(matches!(err, Cw20FactoryError::UnsupportedMessage { .. }));
+    }
+
+    #[test]
+    fn t_2_custom_mapping() {
+        let map = |msg: CosmosMsg<Empty>| -> ContractResult<CosmosMsg<ChainMsg>> {
+            match msg {
+                CosmosMsg::Custom(Empty {}) => Ok(CosmosMsg::Custom(ChainMsg::Noop {})),
+                msg => map_msg(msg),
+            }
+        };
+
+        let response = Response::<Empty>::new()
+            .add_message(bank_msg())
+            .add_message(CosmosMsg::Custom(Empty {}))
+            .to_custom_with(&map)
+            .unwrap();
+
+        assert_eq!(response.messages.len(), 2);
+        assert_eq!(
+            response.messages[1].msg,
+            CosmosMsg::Custom(ChainMsg::Noop {})
+        );
+    }
+
+    #[test]
+    fn t_3_faithful_response() {
+        let original = Response::<Empty>::new()
+            .add_message(bank_msg())
+            .add_submessage(SubMsg::reply_on_success(bank_msg(), 7).with_gas_limit(100_000))
+            .add_submessage(SubMsg::reply_always(bank_msg(), 8))
+            .add_attribute("action", "test")
+            .add_event(Event::new("event").add_attribute("key", "value"));
+
+        let converted: Response<ChainMsg> = original.clone().to_custom().unwrap();
+
+        assert_eq!(converted.messages.len(), original.messages.len());
+
+        for (converted, original) in converted.messages.iter().zip(&original.messages) {
+            assert_eq!(converted.id, original.id);
+            assert_eq!(converted.gas_limit, original.gas_limit);
+            assert_eq!(converted.reply_on, original.reply_on);
+            assert_eq!(converted.msg, map_msg(original.msg.clone()).unwrap());
+        }
+
+        assert_eq!(converted.attributes, original.attributes);
+        assert_eq!(converted.events, original.events);
+        assert_eq!(converted.data, None);
+
+        let converted: Response<ChainMsg> = original
+            .set_data(Binary::from(b"data"))
+            .to_custom()
+            .unwrap();
+
+        assert_eq!(converted.data, Some(Binary::from(b"data")));
     }
 }
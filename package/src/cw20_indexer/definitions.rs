@@ -0,0 +1,33 @@
+use cosmwasm_schema::cw_serde;
+
+/// Format of the denoms accepted by the indexer, chosen at instantiate
+#[cw_serde]
+pub enum DenomScheme {
+    /// `factory/{cw20}/{subdenom}`
+    Tokenfactory,
+    /// `Coreum` `assetft`: `{subunit}-{cw20}`
+    Coreum,
+    /// `{prefix}{cw20}`, like the `peggy{address}` denoms on `Injective`
+    Prefixed { prefix: String },
+    /// `Kujira` `denom` module: `factory/{cw20}/{subdenom}`, created paying the creation fee
+    Kujira,
+}
+
+/// Source of the `TokenDetails` returned by `QueryMsg::TokensInfo`
+#[cw_serde]
+#[derive(Default)]
+pub enum QueryMode {
+    /// Query every `cw20` for its info and supply
+    #[default]
+    Live,
+    /// Read the details cached by the indexer, without cross-contract queries.
+    /// Supplies are as of `TokenDetails::updated_at`. Tokens without cache fall back on `Live`
+    Cached,
+}
+
+impl DenomScheme {
+    /// Schemes used when none is provided at instantiate
+    pub fn defaults() -> Vec<DenomScheme> {
+        vec![DenomScheme::Tokenfactory, DenomScheme::Coreum]
+    }
+}
@@ -9,15 +9,27 @@ pub enum Cw20IndexerError {
     #[error("{0}")]
     Std(#[from] StdError),
 
-    #[error("Invalid denom lenght: denom {denom} is not format factory/contract/subdenom")]
-    InvalidDenomFormatLenght { denom: String },
+    #[error("Invalid denom lenght: denom {denom} is not format {format}")]
+    InvalidDenomFormatLenght { denom: String, format: String },
 
-    #[error("Invalid denom data: denom {denom} is not format factory/contract/subdenom")]
-    InvalidDenomFormatData { denom: String },
+    #[error("Invalid denom data: denom {denom} is not format {format}")]
+    InvalidDenomFormatData { denom: String, format: String },
+
+    #[error("Denom {denom} doesn't match any denom scheme: {reasons}")]
+    DenomSchemeNotMatched { denom: String, reasons: String },
+
+    #[error("At least one denom scheme is required")]
+    EmptyDenomSchemes {},
 
     #[error("Denom already saved: {denom}")]
     DenomAlredySaved { denom: String },
 
+    #[error("Cw20 already registered: {cw20} is registered with denom {denom}")]
+    Cw20AlreadyRegistered { cw20: Addr, denom: String },
+
     #[error("Denom not found: denom not found for cw20 {cw20}")]
     DenomNotFound { cw20: Addr },
+
+    #[error("Unauthorized: only the registered cw20 or the indexer admin can deregister {denom}")]
+    Unauthorized { denom: String },
 }
@@ -2,12 +2,28 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Uint128;
 use rhaki_cw_plus::storage::StorageOrder;
 
+use super::definitions::{DenomScheme, QueryMode};
+
 #[cw_serde]
-pub struct InstantiateMsg {}
+pub struct InstantiateMsg {
+    /// Accepted denom schemes, tried in order. Defaults to `DenomScheme::defaults`
+    pub denom_schemes: Option<Vec<DenomScheme>>,
+}
 
 #[cw_serde]
 pub enum ExecuteMsg {
     RegisterDenom(RegisterDenomMsg),
+    /// Refresh the cached details of a registered `denom` querying its `cw20`.
+    /// Anyone can execute it
+    RefreshDenom {
+        denom: String,
+    },
+    /// Remove a registered `denom`, allowing it or its `cw20` to be registered again.
+    /// Only the registered `cw20` or the indexer admin can execute it.
+    /// No-op if `denom` is not registered
+    DeregisterDenom {
+        denom: String,
+    },
 }
 
 #[cw_serde]
@@ -15,10 +31,26 @@ pub enum QueryMsg {
     TokenInfo {
         denom: String,
     },
+    /// Returns the `TokenDetails` of the `cw20` registered under `denom`
+    TokenDetails {
+        denom: String,
+    },
     TokensInfo {
         start_after: Option<String>,
         limit: Option<u32>,
         order: Option<StorageOrder>,
+        /// Defaults to `QueryMode::Live`
+        mode: Option<QueryMode>,
+    },
+    /// Returns the `TokenDetails` of `cw20_addr`
+    TokenInfoByCw20 {
+        cw20_addr: String,
+    },
+    /// Returns the registered tokens ordered by `cw20` address. Supports pagination.
+    DenomsByCw20 {
+        start_after: Option<String>,
+        limit: Option<u32>,
+        order: Option<StorageOrder>,
     },
 }
 
@@ -30,6 +62,12 @@ pub struct RegisterDenomMsg {
     pub denom: String,
 }
 
+#[cw_serde]
+pub struct DenomByCw20Response {
+    pub cw20_addr: String,
+    pub denom: String,
+}
+
 #[cw_serde]
 pub struct TokenDetails {
     pub cw20_addr: String,
@@ -40,4 +78,10 @@ pub struct TokenDetails {
     pub total_supply: Uint128,
     pub cw20_supply: Uint128,
     pub native_supply: Uint128,
+    /// Scheme matched by `native_denom` on registration
+    pub scheme: DenomScheme,
+    /// Block height of the registration. `None` if registered before the cache was introduced
+    pub registered_at: Option<u64>,
+    /// Block height of the last cache refresh. `None` if never cached
+    pub updated_at: Option<u64>,
 }
@@ -1,81 +1,261 @@
-use cosmwasm_std::{testing::MockStorage, Addr, Coin, CosmosMsg, WasmMsg};
-use cw20_base::msg::{InstantiateMsg as Cw20BaseInstantiateMsg, MigrateMsg};
+use std::fmt::Debug;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::{
+    testing::MockStorage, Addr, BankMsg, Binary, Coin, CosmosMsg, CustomMsg, CustomQuery, Empty,
+    Uint128, WasmMsg,
+};
+use cw20::{Expiration, Logo, MinterResponse, TokenInfoResponse};
+use cw20_base::msg::InstantiateMsg as Cw20BaseInstantiateMsg;
 use cw20_factory_pkg::{
     cw20_factory::{
-        definitions::TransmuteIntoMsg,
+        definitions::{ClawbackAdmin, NativeAdmin, SupplyCapUpdate, TransmuteIntoMsg},
+        msgs::{
+            BlocklistResponse, ExecuteMsg, HealthResponse, IndexersResponse, InitNativeDetails,
+            InstantiateMsg as FactoryInstantiateMsg, MigrateMsg as FactoryMigrateMsg,
+            QueryMsg as FactoryQueryMsg, SupplyDetailsResponse, SupplyReconciliationResponse,
+        },
+    },
+    cw20_indexer::{
+        definitions::QueryMode,
         msgs::{
-            ExecuteMsg, InstantiateMsg as FactoryInstantiateMsg, QueryMsg as FactoryQueryMsg,
-            SupplyDetailsResponse,
+            DenomByCw20Response, ExecuteMsg as IndexerExecuteMsg,
+            InstantiateMsg as IndexerInstantiateMsg, QueryMsg as IndexerQueryMsg, RegisterDenomMsg,
+            TokenDetails,
         },
     },
-    cw20_indexer::msgs::InstantiateMsg as IndexerInstantiateMsg,
 };
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
 use rhaki_cw_plus::{
     asset::AssetPrecisioned,
     cw_asset::AssetInfo,
     multi_test::{
         custom_app::CModuleWrapper,
-        custom_chains::osmosis::{build_osmosis_app, OsmosisStargateModule},
         helper::{
             anyhow::Result as AnyResult,
             create_code,
             cw_multi_test::{
-                addons::MockApiBech32, App, AppResponse, BankKeeper, DistributionKeeper, Executor,
-                GovFailingModule, IbcFailingModule, StakeKeeper,
+                addons::{MockAddressGenerator, MockApiBech32},
+                no_init, App, AppBuilder, AppResponse, Contract, ContractWrapper,
+                DistributionKeeper, Executor, GovFailingModule, IbcFailingModule, Module,
+                StakeKeeper, Stargate, StargateFailing, WasmKeeper,
             },
-            Bench32AppExt, DefaultWasmKeeper, FailingCustom,
+            Bench32AppExt, FailingCustom,
         },
     },
+    storage::interfaces::ItemInterface,
     traits::Wrapper,
     wasm::WasmMsgBuilder,
 };
 
+use crate::mocks::{
+    bank::HookedBankKeeper,
+    coreum::CoreumModule,
+    injective::InjectiveStargateMock,
+    kujira::{KujiraModule, KujiraStargateMock},
+    neutron::NeutronModule,
+    osmosis::OsmosisStargateMock,
+    receiver,
+    sei::SeiStargateMock,
+};
+
 pub struct Def {
     pub owner: Addr,
     pub code_id_cw20_factory: u64,
     pub code_id_cw20_base: u64,
+    pub code_id_receiver: u64,
+    pub code_id_indexer: u64,
     pub indexer_addr: Addr,
 }
 
-pub type OsmosisApp = App<
-    BankKeeper,
+/// Custom module of the chain under test
+pub trait TestCustom:
+    Module<
+    ExecT: CustomMsg + DeserializeOwned + 'static,
+    QueryT: CustomQuery + Debug + DeserializeOwned + 'static,
+>
+{
+}
+
+impl<T> TestCustom for T where
+    T: Module<
+        ExecT: CustomMsg + DeserializeOwned + 'static,
+        QueryT: CustomQuery + Debug + DeserializeOwned + 'static,
+    >
+{
+}
+
+pub type CustomApp<C, S> = App<
+    HookedBankKeeper,
     MockApiBech32,
     MockStorage,
-    FailingCustom,
-    DefaultWasmKeeper,
+    C,
+    WasmKeeper<<C as Module>::ExecT, <C as Module>::QueryT>,
     StakeKeeper,
     DistributionKeeper,
     IbcFailingModule,
     GovFailingModule,
-    OsmosisStargateModule,
+    S,
 >;
 
+pub type OsmosisApp = CustomApp<FailingCustom, OsmosisStargateMock>;
+
+pub type InjectiveApp = CustomApp<FailingCustom, InjectiveStargateMock>;
+
+pub type KujiraApp = CustomApp<KujiraModule, KujiraStargateMock>;
+
+pub type NeutronApp = CustomApp<NeutronModule, OsmosisStargateMock>;
+
+pub type SeiApp = CustomApp<FailingCustom, SeiStargateMock>;
+
+pub type CoreumApp = CustomApp<CoreumModule, StargateFailing>;
+
 pub fn startup_osmosis() -> (OsmosisApp, CModuleWrapper, Def) {
-    let (mut app, db) = build_osmosis_app();
+    startup(
+        "osmo",
+        FailingCustom::default(),
+        OsmosisStargateMock::default(),
+        Box::new(
+            create_code(
+                cw20_factory_osmosis::instantiate,
+                cw20_factory_osmosis::execute,
+                cw20_factory_osmosis::query,
+            )
+            .with_migrate(cw20_factory_osmosis::migrate)
+            .with_reply(cw20_factory_osmosis::reply)
+            .with_sudo(cw20_factory_osmosis::sudo),
+        ),
+    )
+}
+
+pub fn startup_injective() -> (InjectiveApp, CModuleWrapper, Def) {
+    startup(
+        "inj",
+        FailingCustom::default(),
+        InjectiveStargateMock::default(),
+        Box::new(
+            create_code(
+                cw20_factory_injective::instantiate,
+                cw20_factory_injective::execute,
+                cw20_factory_injective::query,
+            )
+            .with_migrate(cw20_factory_injective::migrate)
+            .with_reply(cw20_factory_injective::reply)
+            .with_sudo(cw20_factory_injective::sudo),
+        ),
+    )
+}
+
+pub fn startup_kujira() -> (KujiraApp, CModuleWrapper, Def) {
+    startup(
+        "kujira",
+        KujiraModule::default(),
+        KujiraStargateMock::default(),
+        Box::new(
+            create_code(
+                cw20_factory_kujira::instantiate,
+                cw20_factory_kujira::execute,
+                cw20_factory_kujira::query,
+            )
+            .with_migrate(cw20_factory_kujira::migrate)
+            .with_reply(cw20_factory_kujira::reply),
+        ),
+    )
+}
+
+pub fn startup_neutron() -> (NeutronApp, CModuleWrapper, Def) {
+    startup(
+        "neutron",
+        NeutronModule::default(),
+        OsmosisStargateMock::default(),
+        Box::new(
+            create_code(
+                cw20_factory_neutron::instantiate,
+                cw20_factory_neutron::execute,
+                cw20_factory_neutron::query,
+            )
+            .with_migrate(cw20_factory_neutron::migrate)
+            .with_reply(cw20_factory_neutron::reply)
+            .with_sudo(cw20_factory_neutron::sudo),
+        ),
+    )
+}
+
+pub fn startup_sei() -> (SeiApp, CModuleWrapper, Def) {
+    startup(
+        "sei",
+        FailingCustom::default(),
+        SeiStargateMock::default(),
+        Box::new(
+            create_code(
+                cw20_factory_sei::instantiate,
+                cw20_factory_sei::execute,
+                cw20_factory_sei::query,
+            )
+            .with_migrate(cw20_factory_sei::migrate)
+            .with_reply(cw20_factory_sei::reply),
+        ),
+    )
+}
+
+pub fn startup_coreum() -> (CoreumApp, CModuleWrapper, Def) {
+    startup(
+        "core",
+        CoreumModule::default(),
+        StargateFailing,
+        Box::new(
+            create_code(
+                cw20_factory_coreum::instantiate,
+                cw20_factory_coreum::execute,
+                cw20_factory_coreum::query,
+            )
+            .with_migrate(cw20_factory_coreum::migrate)
+            .with_reply(cw20_factory_coreum::reply),
+        ),
+    )
+}
+
+/// Build the app with the chain modules, store the codes and instantiate the indexer.
+///
+/// Codes returning `Response<Empty>` are adapted to the custom message of the chain
+fn startup<C: TestCustom, S: Stargate>(
+    prefix: &'static str,
+    custom: C,
+    stargate: S,
+    cw20_factory_code: Box<dyn Contract<C::ExecT, C::QueryT>>,
+) -> (CustomApp<C, S>, CModuleWrapper, Def) {
+    let mut app = AppBuilder::new_custom()
+        .with_api(MockApiBech32::new(prefix))
+        .with_wasm(WasmKeeper::default().with_address_generator(MockAddressGenerator))
+        .with_bank(HookedBankKeeper::default())
+        .with_custom(custom)
+        .with_stargate(stargate)
+        .build(no_init);
+
+    let db = CModuleWrapper::default();
 
     let code_id_cw20_base = app.store_code(Box::new(
-        create_code(
-            cw20_base::contract::instantiate,
+        ContractWrapper::new_with_empty(
             cw20_base::contract::execute,
+            cw20_base::contract::instantiate,
             cw20_base::contract::query,
         )
-        .with_migrate(cw20_base::contract::migrate),
+        .with_migrate_empty(cw20_base::contract::migrate),
     ));
 
-    let code_id_cw20_factory = app.store_code(Box::new(
-        create_code(
-            cw20_factory_osmosis::instantiate,
-            cw20_factory_osmosis::execute,
-            cw20_factory_osmosis::query,
-        )
-        .with_migrate(cw20_factory_osmosis::migrate),
-    ));
+    let code_id_cw20_factory = app.store_code(cw20_factory_code);
 
-    let code_id_indexer = app.store_code(create_code(
-        cw20_factory_indexer::contract::instantiate,
+    let code_id_indexer = app.store_code(Box::new(ContractWrapper::new_with_empty(
         cw20_factory_indexer::contract::execute,
+        cw20_factory_indexer::contract::instantiate,
         cw20_factory_indexer::contract::query,
-    ));
+    )));
+
+    let code_id_receiver = app.store_code(Box::new(ContractWrapper::new_with_empty(
+        receiver::execute,
+        receiver::instantiate,
+        receiver::query,
+    )));
 
     let owner = app.generate_addr("owner");
 
@@ -83,7 +263,9 @@ pub fn startup_osmosis() -> (OsmosisApp, CModuleWrapper, Def) {
         .instantiate_contract(
             code_id_indexer,
             owner.clone(),
-            &IndexerInstantiateMsg {},
+            &IndexerInstantiateMsg {
+                denom_schemes: None,
+            },
             &[],
             "indexer",
             owner.to_string().wrap_some(),
@@ -94,14 +276,40 @@ pub fn startup_osmosis() -> (OsmosisApp, CModuleWrapper, Def) {
         owner,
         code_id_cw20_factory,
         code_id_cw20_base,
+        code_id_receiver,
+        code_id_indexer,
         indexer_addr,
     };
 
     (app, db, def)
 }
 
-pub fn create_cw20_factory(
-    app: &mut OsmosisApp,
+/// Instantiate msg of the `FOO` token minted by the owner, with the native denom created without indexer.
+///
+/// Tests override the fields they need with the struct update syntax
+pub fn default_factory_init(def: &Def) -> FactoryInstantiateMsg {
+    FactoryInstantiateMsg {
+        name: "Token Foo".to_string(),
+        symbol: "FOO".to_string(),
+        decimals: 6,
+        initial_balances: vec![],
+        mint: MinterResponse {
+            minter: def.owner.to_string(),
+            cap: None,
+        }
+        .wrap_some(),
+        marketing: None,
+        init_native: InitNativeDetails::WithoutIndexer.wrap_some(),
+        subdenom: None,
+        create_native_authority: None,
+        clawback_admin: None,
+        native_cap: None,
+        cw20_cap: None,
+    }
+}
+
+pub fn create_cw20_factory<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     def: &Def,
     msg: FactoryInstantiateMsg,
     funds: Vec<Coin>,
@@ -115,11 +323,13 @@ pub fn create_cw20_factory(
         def.owner.to_string().wrap_some(),
     )?;
 
+    assert_supply_invariant(app, &token_addr);
+
     Ok(token_addr)
 }
 
-pub fn create_cw20_base(
-    app: &mut OsmosisApp,
+pub fn create_cw20_base<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     def: &Def,
     msg: Cw20BaseInstantiateMsg,
     funds: Vec<Coin>,
@@ -136,29 +346,35 @@ pub fn create_cw20_base(
     Ok(token_addr)
 }
 
-pub fn migrate_to_factory(
-    app: &mut OsmosisApp,
+pub fn migrate_to_factory<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     def: &Def,
     cw20_addr: &Addr,
+    msg: FactoryMigrateMsg,
 ) -> AnyResult<AppResponse> {
-    app.migrate_contract(
+    let response = app.migrate_contract(
         def.owner.clone(),
         cw20_addr.clone(),
-        &MigrateMsg {},
+        &msg,
         def.code_id_cw20_factory,
-    )
+    );
+
+    with_supply_invariant(app, cw20_addr, response)
 }
 
-pub fn transmute(
-    app: &mut OsmosisApp,
+pub fn transmute<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     sender: &Addr,
     token_addr: &Addr,
     amount: AssetPrecisioned,
 ) -> AnyResult<AppResponse> {
-    let msg: CosmosMsg = match amount.info() {
+    let msg: CosmosMsg<C::ExecT> = match amount.info() {
         rhaki_cw_plus::cw_asset::AssetInfoBase::Native(_) => WasmMsg::build_execute(
             token_addr,
-            ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Cw20 {}),
+            ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Cw20 {
+                recipient: None,
+                hook: None,
+            }),
             vec![amount.try_into().unwrap()],
         )
         .unwrap()
@@ -167,6 +383,8 @@ pub fn transmute(
             token_addr,
             ExecuteMsg::TransmuteInto(TransmuteIntoMsg::Native {
                 amount: amount.amount_raw(),
+                recipient: None,
+                hook: None,
             }),
             vec![],
         )
@@ -175,28 +393,444 @@ pub fn transmute(
         _ => todo!(),
     };
 
-    app.execute(sender.clone(), msg)
+    let response = app.execute(sender.clone(), msg);
+
+    with_supply_invariant(app, token_addr, response)
 }
 
-pub fn qy_factory_denom(app: &OsmosisApp, cw20_addr: &Addr) -> String {
+pub fn transmute_into<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    token_addr: &Addr,
+    msg: TransmuteIntoMsg,
+    funds: Vec<Coin>,
+) -> AnyResult<AppResponse> {
+    let response = app.execute_contract(
+        sender.clone(),
+        token_addr.clone(),
+        &ExecuteMsg::TransmuteInto(msg),
+        &funds,
+    );
+
+    with_supply_invariant(app, token_addr, response)
+}
+
+pub fn create_receiver<C: TestCustom, S: Stargate>(app: &mut CustomApp<C, S>, def: &Def) -> Addr {
+    app.instantiate_contract(
+        def.code_id_receiver,
+        def.owner.clone(),
+        &Empty {},
+        &[],
+        "receiver",
+        None,
+    )
+    .unwrap()
+}
+
+pub fn qy_factory_denom<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> String {
     app.wrap()
         .query_wasm_smart(cw20_addr, &FactoryQueryMsg::FactoryDenom {})
         .unwrap()
 }
 
-pub fn qy_supply(app: &OsmosisApp, cw20_addr: &Addr) -> SupplyDetailsResponse {
+pub fn qy_indexer_tokens_info<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    def: &Def,
+    mode: QueryMode,
+) -> Vec<TokenDetails> {
+    app.wrap()
+        .query_wasm_smart(
+            &def.indexer_addr,
+            &IndexerQueryMsg::TokensInfo {
+                start_after: None,
+                limit: None,
+                order: None,
+                mode: mode.wrap_some(),
+            },
+        )
+        .unwrap()
+}
+
+pub fn qy_indexer_token_info<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    def: &Def,
+    denom: &str,
+) -> TokenInfoResponse {
+    app.wrap()
+        .query_wasm_smart(
+            &def.indexer_addr,
+            &IndexerQueryMsg::TokenInfo {
+                denom: denom.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub fn qy_indexer_token_details<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    def: &Def,
+    denom: &str,
+) -> TokenDetails {
+    app.wrap()
+        .query_wasm_smart(
+            &def.indexer_addr,
+            &IndexerQueryMsg::TokenDetails {
+                denom: denom.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+pub fn qy_indexer_token_info_by_cw20<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    def: &Def,
+    cw20_addr: &Addr,
+) -> AnyResult<TokenDetails> {
+    Ok(app.wrap().query_wasm_smart(
+        &def.indexer_addr,
+        &IndexerQueryMsg::TokenInfoByCw20 {
+            cw20_addr: cw20_addr.to_string(),
+        },
+    )?)
+}
+
+pub fn qy_indexer_denoms_by_cw20<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    def: &Def,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+) -> Vec<DenomByCw20Response> {
+    app.wrap()
+        .query_wasm_smart(
+            &def.indexer_addr,
+            &IndexerQueryMsg::DenomsByCw20 {
+                start_after: start_after.map(|addr| addr.to_string()),
+                limit,
+                order: None,
+            },
+        )
+        .unwrap()
+}
+
+pub fn register_denom<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    def: &Def,
+    sender: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        def.indexer_addr.clone(),
+        &IndexerExecuteMsg::RegisterDenom(RegisterDenomMsg {
+            denom: denom.to_string(),
+        }),
+        &[],
+    )
+}
+
+pub fn refresh_denom<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    def: &Def,
+    sender: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        def.indexer_addr.clone(),
+        &IndexerExecuteMsg::RefreshDenom {
+            denom: denom.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn refresh_indexers<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    start_after: Option<&Addr>,
+    limit: Option<u32>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::RefreshIndexers {
+            start_after: start_after.map(|addr| addr.to_string()),
+            limit,
+        },
+        &[],
+    )
+}
+
+pub fn create_indexer<C: TestCustom, S: Stargate>(app: &mut CustomApp<C, S>, def: &Def) -> Addr {
+    app.instantiate_contract(
+        def.code_id_indexer,
+        def.owner.clone(),
+        &IndexerInstantiateMsg {
+            denom_schemes: None,
+        },
+        &[],
+        "indexer",
+        def.owner.to_string().wrap_some(),
+    )
+    .unwrap()
+}
+
+pub fn deregister_denom<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    def: &Def,
+    sender: &Addr,
+    denom: &str,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        def.indexer_addr.clone(),
+        &IndexerExecuteMsg::DeregisterDenom {
+            denom: denom.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn register_to_indexer<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    indexer_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::RegisterToIndexer {
+            indexer_addr: indexer_addr.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn unregister_from_indexer<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    def: &Def,
+    sender: &Addr,
+    cw20_addr: &Addr,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UnregisterFromIndexer {
+            indexer_addr: def.indexer_addr.to_string(),
+        },
+        &[],
+    )
+}
+
+pub fn qy_indexers<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> Vec<String> {
+    app.wrap()
+        .query_wasm_smart::<IndexersResponse>(
+            cw20_addr,
+            &FactoryQueryMsg::Indexers {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+        .indexers
+}
+
+pub fn reconcile<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    flag: bool,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::Reconcile { flag },
+        &[],
+    )
+}
+
+pub fn update_supply_caps<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    native_cap: Option<SupplyCapUpdate>,
+    cw20_cap: Option<SupplyCapUpdate>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UpdateSupplyCaps {
+            native_cap,
+            cw20_cap,
+        },
+        &[],
+    )
+}
+
+/// Assert that the `cw20` balances, summed through the `Health` pages, match the `cw20` supply.
+///
+/// Run by the execute helpers, tokens not answering `Health` (like `cw20-base`) are skipped
+pub fn assert_supply_invariant<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) {
+    let mut balances_sum = Uint128::zero();
+    let mut start_after = None;
+
+    loop {
+        let health = match app.wrap().query_wasm_smart::<HealthResponse>(
+            cw20_addr,
+            &FactoryQueryMsg::Health {
+                start_after: start_after.clone(),
+                limit: None,
+            },
+        ) {
+            Ok(health) => health,
+            Err(_) if start_after.is_none() => return,
+            Err(err) => panic!("{err}"),
+        };
+
+        balances_sum += health.balances_sum;
+
+        match health.last_address {
+            Some(last_address) => start_after = Some(last_address),
+            None => {
+                assert_eq!(
+                    balances_sum, health.cw20_supply,
+                    "cw20 balances don't sum up to the cw20 supply"
+                );
+                return;
+            }
+        }
+    }
+}
+
+fn with_supply_invariant<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+    response: AnyResult<AppResponse>,
+) -> AnyResult<AppResponse> {
+    if response.is_ok() {
+        assert_supply_invariant(app, cw20_addr);
+    }
+
+    response
+}
+
+pub fn qy_health<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+    start_after: Option<&str>,
+    limit: Option<u32>,
+) -> HealthResponse {
+    app.wrap()
+        .query_wasm_smart(
+            cw20_addr,
+            &FactoryQueryMsg::Health {
+                start_after: start_after.map(|address| address.to_string()),
+                limit,
+            },
+        )
+        .unwrap()
+}
+
+pub fn qy_supply_reconciliation<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> SupplyReconciliationResponse {
+    app.wrap()
+        .query_wasm_smart(cw20_addr, &FactoryQueryMsg::SupplyReconciliation {})
+        .unwrap()
+}
+
+pub fn qy_supply<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> SupplyDetailsResponse {
     app.wrap()
         .query_wasm_smart(cw20_addr, &FactoryQueryMsg::SupplyDetails {})
         .unwrap()
 }
 
-pub fn burn(
-    app: &mut OsmosisApp,
+pub fn qy_native_admin<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> NativeAdmin {
+    app.wrap()
+        .query_wasm_smart(cw20_addr, &FactoryQueryMsg::NativeAdmin {})
+        .unwrap()
+}
+
+pub fn qy_clawback_admin<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> ClawbackAdmin {
+    app.wrap()
+        .query_wasm_smart(cw20_addr, &FactoryQueryMsg::ClawbackAdmin {})
+        .unwrap()
+}
+
+pub fn qy_paused<C: TestCustom, S: Stargate>(app: &CustomApp<C, S>, cw20_addr: &Addr) -> bool {
+    app.wrap()
+        .query_wasm_smart(cw20_addr, &FactoryQueryMsg::Paused {})
+        .unwrap()
+}
+
+pub fn qy_blocklist<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    cw20_addr: &Addr,
+) -> Vec<String> {
+    app.wrap()
+        .query_wasm_smart::<BlocklistResponse>(
+            cw20_addr,
+            &FactoryQueryMsg::Blocklist {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+        .addresses
+}
+
+pub fn qy_tokenfactory_admin<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    denom: &str,
+) -> Option<Addr> {
+    CModuleWrapper::load(app.storage())
+        .unwrap()
+        .token_factory
+        .admin
+        .get(denom)
+        .cloned()
+}
+
+pub fn qy_native_metadata<C: TestCustom, S: Stargate>(
+    app: &CustomApp<C, S>,
+    denom: &str,
+) -> Option<Metadata> {
+    CModuleWrapper::load(app.storage())
+        .unwrap()
+        .token_factory
+        .metadata
+        .get(denom)
+        .cloned()
+}
+
+pub fn burn<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     sender: &Addr,
     token_addr: &Addr,
     amount: AssetPrecisioned,
 ) -> AnyResult<AppResponse> {
-    let msg: CosmosMsg = match amount.info() {
+    let msg: CosmosMsg<C::ExecT> = match amount.info() {
         rhaki_cw_plus::cw_asset::AssetInfoBase::Native(_) => WasmMsg::build_execute(
             token_addr,
             ExecuteMsg::Burn { amount: None },
@@ -216,12 +850,14 @@ pub fn burn(
         _ => todo!(),
     };
 
-    app.execute(sender.clone(), msg)
+    let response = app.execute(sender.clone(), msg);
+
+    with_supply_invariant(app, token_addr, response)
 }
 
 #[allow(clippy::needless_bool)]
-pub fn mint(
-    app: &mut OsmosisApp,
+pub fn mint<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     def: &Def,
     to: &Addr,
     token_addr: &Addr,
@@ -243,19 +879,252 @@ pub fn mint(
     )
     .unwrap();
 
-    app.execute(def.owner.clone(), msg.into())
+    let response = app.execute(def.owner.clone(), msg.into());
+
+    with_supply_invariant(app, token_addr, response)
 }
 
-pub fn create_native(
-    app: &mut OsmosisApp,
+pub fn create_native<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
     sender: &Addr,
     cw20_addr: &Addr,
+    subdenom: Option<String>,
     funds: Vec<Coin>,
 ) -> AnyResult<AppResponse> {
     app.execute_contract(
         sender.clone(),
         cw20_addr.clone(),
-        &ExecuteMsg::CreateNative {},
+        &ExecuteMsg::CreateNative { subdenom },
         &funds,
     )
 }
+
+pub fn update_marketing<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    description: Option<String>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UpdateMarketing {
+            project: None,
+            description,
+            marketing: None,
+        },
+        &[],
+    )
+}
+
+pub fn upload_logo<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    logo: Logo,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UploadLogo(logo),
+        &[],
+    )
+}
+
+pub fn update_native_admin<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    new_admin: Option<&Addr>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UpdateNativeAdmin {
+            new_admin: new_admin.map(|admin| admin.to_string()),
+        },
+        &[],
+    )
+}
+
+pub fn set_paused<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    paused: bool,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::SetPaused { paused },
+        &[],
+    )
+}
+
+pub fn update_blocklist<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    add: Vec<&Addr>,
+    remove: Vec<&Addr>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UpdateBlocklist {
+            add: add.into_iter().map(|addr| addr.to_string()).collect(),
+            remove: remove.into_iter().map(|addr| addr.to_string()).collect(),
+        },
+        &[],
+    )
+}
+
+/// Transfer `cw20` with `ExecuteMsg::Transfer` or `native` with `BankMsg::Send`
+pub fn transfer<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    recipient: &Addr,
+    amount: AssetPrecisioned,
+) -> AnyResult<AppResponse> {
+    let cw20_addr = match amount.info() {
+        AssetInfo::Cw20(token_addr) => Some(token_addr.clone()),
+        _ => None,
+    };
+
+    let msg: CosmosMsg<C::ExecT> = match amount.info() {
+        AssetInfo::Native(_) => BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![amount.try_into().unwrap()],
+        }
+        .into(),
+        AssetInfo::Cw20(token_addr) => WasmMsg::build_execute(
+            token_addr,
+            ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount: amount.amount_raw(),
+            },
+            vec![],
+        )
+        .unwrap()
+        .into(),
+        _ => unimplemented!(),
+    };
+
+    let response = app.execute(sender.clone(), msg);
+
+    match cw20_addr {
+        Some(cw20_addr) => with_supply_invariant(app, &cw20_addr, response),
+        None => response,
+    }
+}
+
+pub fn force_transfer<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    token_addr: &Addr,
+    from: &Addr,
+    to: &Addr,
+    amount: AssetPrecisioned,
+) -> AnyResult<AppResponse> {
+    let response = app.execute_contract(
+        sender.clone(),
+        token_addr.clone(),
+        &ExecuteMsg::ForceTransfer {
+            from: from.to_string(),
+            to: to.to_string(),
+            amount: amount.amount_raw(),
+            as_native: matches!(amount.info(), AssetInfo::Native(_)).wrap_some(),
+        },
+        &[],
+    );
+
+    with_supply_invariant(app, token_addr, response)
+}
+
+pub fn update_clawback_admin<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    cw20_addr: &Addr,
+    new_admin: Option<&Addr>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        sender.clone(),
+        cw20_addr.clone(),
+        &ExecuteMsg::UpdateClawbackAdmin {
+            new_admin: new_admin.map(|admin| admin.to_string()),
+        },
+        &[],
+    )
+}
+
+pub fn increase_allowance<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    owner: &Addr,
+    token_addr: &Addr,
+    spender: &Addr,
+    amount: AssetPrecisioned,
+    expires: Option<Expiration>,
+) -> AnyResult<AppResponse> {
+    app.execute_contract(
+        owner.clone(),
+        token_addr.clone(),
+        &ExecuteMsg::IncreaseAllowance {
+            spender: spender.to_string(),
+            amount: amount.amount_raw(),
+            expires,
+        },
+        &[],
+    )
+}
+
+pub fn transmute_from<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    owner: &Addr,
+    recipient: &Addr,
+    amount: AssetPrecisioned,
+) -> AnyResult<AppResponse> {
+    let token_addr = match amount.info() {
+        AssetInfo::Cw20(token_addr) => token_addr.clone(),
+        _ => unimplemented!(),
+    };
+
+    let response = app.execute_contract(
+        sender.clone(),
+        token_addr.clone(),
+        &ExecuteMsg::TransmuteFrom {
+            owner: owner.to_string(),
+            amount: amount.amount_raw(),
+            recipient: recipient.to_string(),
+        },
+        &[],
+    );
+
+    with_supply_invariant(app, &token_addr, response)
+}
+
+pub fn send<C: TestCustom, S: Stargate>(
+    app: &mut CustomApp<C, S>,
+    sender: &Addr,
+    contract: &Addr,
+    amount: AssetPrecisioned,
+    msg: Binary,
+) -> AnyResult<AppResponse> {
+    let token_addr = match amount.info() {
+        AssetInfo::Cw20(token_addr) => token_addr.clone(),
+        _ => unimplemented!(),
+    };
+
+    let response = app.execute_contract(
+        sender.clone(),
+        token_addr.clone(),
+        &ExecuteMsg::Send {
+            contract: contract.to_string(),
+            amount: amount.amount_raw(),
+            msg,
+        },
+        &[],
+    );
+
+    with_supply_invariant(app, &token_addr, response)
+}
@@ -3,3 +3,6 @@ mod helper;
 
 #[cfg(test)]
 mod integration_tests;
+
+#[cfg(test)]
+mod mocks;
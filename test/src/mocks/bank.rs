@@ -0,0 +1,108 @@
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, BankQuery, Binary, BlockInfo, CustomQuery, Querier, Storage,
+};
+use cw20_factory_pkg::cw20_factory::msgs::SudoMsg as FactorySudoMsg;
+use cw_storage_plus::Map;
+use rhaki_cw_plus::multi_test::helper::{
+    anyhow::Result as AnyResult,
+    cw_multi_test::{
+        AppResponse, Bank, BankKeeper, BankSudo, CosmosRouter, Module, SudoMsg, WasmSudo,
+    },
+};
+
+/// Before send hooks registered on the `tokenfactory` mocks, `denom` -> `contract`
+pub const BEFORE_SEND_HOOKS: Map<&str, Addr> = Map::new("mock_before_send_hooks");
+
+/// Wrap `BankKeeper`, calling the before send hook of the sent denoms
+/// (`TrackBeforeSend` and `BlockBeforeSend`) on every `BankMsg::Send`.
+#[derive(Default)]
+pub struct HookedBankKeeper {
+    inner: BankKeeper,
+}
+
+impl Bank for HookedBankKeeper {}
+
+impl Module for HookedBankKeeper {
+    type ExecT = BankMsg;
+    type QueryT = BankQuery;
+    type SudoT = BankSudo;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: BankMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        if let BankMsg::Send { to_address, amount } = &msg {
+            for coin in amount {
+                if let Some(contract_addr) = BEFORE_SEND_HOOKS.may_load(storage, &coin.denom)? {
+                    let hook_msg = |msg: FactorySudoMsg| -> AnyResult<SudoMsg> {
+                        Ok(SudoMsg::Wasm(WasmSudo {
+                            contract_addr: contract_addr.clone(),
+                            msg: to_json_binary(&msg)?,
+                        }))
+                    };
+
+                    // Errors of TrackBeforeSend are ignored by the tokenfactory module
+                    let _ = router.sudo(
+                        api,
+                        storage,
+                        block,
+                        hook_msg(FactorySudoMsg::TrackBeforeSend {
+                            from: sender.to_string(),
+                            to: to_address.clone(),
+                            amount: coin.clone(),
+                        })?,
+                    );
+
+                    router.sudo(
+                        api,
+                        storage,
+                        block,
+                        hook_msg(FactorySudoMsg::BlockBeforeSend {
+                            from: sender.to_string(),
+                            to: to_address.clone(),
+                            amount: coin.clone(),
+                        })?,
+                    )?;
+                }
+            }
+        }
+
+        self.inner.execute(api, storage, router, block, sender, msg)
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        msg: BankSudo,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.inner.sudo(api, storage, router, block, msg)
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        block: &BlockInfo,
+        request: BankQuery,
+    ) -> AnyResult<Binary> {
+        self.inner.query(api, storage, querier, block, request)
+    }
+}
@@ -0,0 +1,205 @@
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Empty, Querier,
+    Storage, Uint128,
+};
+use cw20_factory_coreum::bindings::{
+    AssetFTMsg, AssetFTQuery, CoreumMsg, CoreumQueries, Params, ParamsResponse,
+};
+use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use rhaki_cw_plus::{
+    multi_test::{
+        custom_app::{CModuleWrapper, ModuleDb},
+        custom_modules::token_factory::CTokenFactoryFee,
+        helper::{
+            anyhow::{bail, Result as AnyResult},
+            cw_multi_test::{AppResponse, CosmosRouter, Module},
+        },
+    },
+    storage::interfaces::ItemInterface,
+};
+
+/// Stand-in for the `Coreum` `assetft` module, driven by `CosmosMsg::Custom(CoreumMsg::AssetFT(..))`.
+///
+/// Shares the `CTokenFactory` state of `CModuleWrapper`:
+/// - the issue fee is the first coin of `fee_creation` and it's burned from the issuer;
+/// - the issuer is the admin of the denom and the metadata is stored at issue time.
+#[derive(Default)]
+pub struct CoreumModule {}
+
+impl Module for CoreumModule {
+    type ExecT = CoreumMsg;
+    type QueryT = CoreumQueries;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: CoreumMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let CoreumMsg::AssetFT(msg) = msg;
+
+        match msg {
+            AssetFTMsg::Issue {
+                symbol,
+                subunit,
+                precision,
+                initial_amount,
+                description,
+                ..
+            } => {
+                let denom = format!("{subunit}-{sender}").to_lowercase();
+
+                let issue_fee = CModuleWrapper::use_db(storage, |db, _| {
+                    if db.token_factory.supplies.contains_key(&denom) {
+                        bail!("denom already issued: {denom}");
+                    }
+
+                    db.token_factory
+                        .supplies
+                        .insert(denom.clone(), Uint128::zero());
+                    db.token_factory.admin.insert(denom.clone(), sender.clone());
+                    db.token_factory.metadata.insert(
+                        denom.clone(),
+                        Metadata {
+                            description: description.unwrap_or_default(),
+                            denom_units: vec![
+                                DenomUnit {
+                                    denom: denom.clone(),
+                                    exponent: 0,
+                                    aliases: vec![],
+                                },
+                                DenomUnit {
+                                    denom: symbol.to_lowercase(),
+                                    exponent: precision,
+                                    aliases: vec![],
+                                },
+                            ],
+                            base: denom.clone(),
+                            display: symbol.to_lowercase(),
+                            name: symbol.clone(),
+                            symbol,
+                            uri: "".to_string(),
+                            uri_hash: "".to_string(),
+                        },
+                    );
+
+                    Ok(issue_fee(&db.token_factory.fee_creation))
+                })??;
+
+                if !issue_fee.amount.is_zero() {
+                    router.execute(
+                        api,
+                        storage,
+                        block,
+                        sender.clone(),
+                        BankMsg::Burn {
+                            amount: vec![issue_fee],
+                        }
+                        .into(),
+                    )?;
+                }
+
+                if initial_amount.is_zero() {
+                    Ok(AppResponse::default())
+                } else {
+                    CModuleWrapper::use_db(storage, |db, storage| {
+                        db.token_factory.run_msg_mint(
+                            api,
+                            storage,
+                            router,
+                            block,
+                            sender.clone(),
+                            denom,
+                            initial_amount,
+                            sender.to_string(),
+                        )
+                    })?
+                }
+            }
+            AssetFTMsg::Mint { coin, recipient } => {
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_msg_mint(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender.clone(),
+                        coin.denom,
+                        coin.amount,
+                        recipient.unwrap_or_else(|| sender.to_string()),
+                    )
+                })?
+            }
+            AssetFTMsg::Burn { coin } => CModuleWrapper::use_db(storage, |db, storage| {
+                db.token_factory.run_burn_denom(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender.clone(),
+                    coin.denom,
+                    coin.amount,
+                    sender.to_string(),
+                )
+            })?,
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: CoreumQueries,
+    ) -> AnyResult<Binary> {
+        let CoreumQueries::AssetFT(request) = request;
+
+        match request {
+            AssetFTQuery::Params {} => {
+                let fee_creation = CModuleWrapper::load(storage)
+                    .unwrap_or_default()
+                    .token_factory
+                    .fee_creation;
+
+                Ok(to_json_binary(&ParamsResponse {
+                    params: Params {
+                        issue_fee: issue_fee(&fee_creation),
+                    },
+                })?)
+            }
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo not handled on CoreumModule")
+    }
+}
+
+/// `assetft` charges a single coin, `0ucore` when no fee is setted
+fn issue_fee(fee_creation: &Option<CTokenFactoryFee>) -> Coin {
+    fee_creation
+        .as_ref()
+        .and_then(|fee_creation| fee_creation.fee.first().cloned())
+        .unwrap_or_else(|| Coin::new(0, "ucore"))
+}
@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, Binary, BlockInfo, CustomQuery, Querier, Storage, Uint128,
+};
+use cw20_factory_injective::proto::{
+    Coin as InjectiveCoin, MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+    Params, QueryParamsResponse, TypeUrl, QUERY_PARAMS_PATH,
+};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
+use prost::Message;
+use rhaki_cw_plus::{
+    multi_test::{
+        custom_app::{CModuleWrapper, ModuleDb},
+        helper::{
+            anyhow::{anyhow, bail, Result as AnyResult},
+            cw_multi_test::{AppResponse, CosmosRouter, Stargate},
+        },
+    },
+    storage::interfaces::ItemInterface,
+};
+
+use crate::mocks::osmosis::assert_admin;
+
+/// Stand-in for the `Injective` `tokenfactory`, sharing the `CTokenFactory` state of `CModuleWrapper`:
+/// - `MsgMint` mints to the sender;
+/// - `MsgBurn` burns from the sender;
+/// - `MsgChangeAdmin` with an empty `new_admin` renounces the admin of the denom;
+/// - `Query/Params` returns the `fee_creation` setted on `CModuleWrapper`.
+#[derive(Default)]
+pub struct InjectiveStargateMock {}
+
+impl Stargate for InjectiveStargateMock {
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match type_url.as_str() {
+            MsgCreateDenom::TYPE_URL => {
+                let msg = MsgCreateDenom::decode(value.as_slice())?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory
+                        .run_create_denom(api, storage, router, block, sender, msg.subdenom)
+                        .map(|res| res.response)
+                })?
+            }
+            MsgMint::TYPE_URL => {
+                let msg = MsgMint::decode(value.as_slice())?;
+                let coin = amount(msg.amount)?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_msg_mint(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender.clone(),
+                        coin.denom,
+                        Uint128::from_str(&coin.amount)?,
+                        sender.to_string(),
+                    )
+                })?
+            }
+            MsgBurn::TYPE_URL => {
+                let msg = MsgBurn::decode(value.as_slice())?;
+                let coin = amount(msg.amount)?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_burn_denom(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender.clone(),
+                        coin.denom,
+                        Uint128::from_str(&coin.amount)?,
+                        sender.to_string(),
+                    )
+                })?
+            }
+            MsgChangeAdmin::TYPE_URL => {
+                let msg = MsgChangeAdmin::decode(value.as_slice())?;
+
+                assert_admin(storage, &sender, &msg.denom)?;
+
+                let new_admin = if msg.new_admin.is_empty() {
+                    None
+                } else {
+                    Some(api.addr_validate(&msg.new_admin)?)
+                };
+
+                CModuleWrapper::use_db(storage, |db, _| match new_admin {
+                    Some(new_admin) => db.token_factory.admin.insert(msg.denom, new_admin),
+                    None => db.token_factory.admin.remove(&msg.denom),
+                })?;
+
+                Ok(AppResponse::default())
+            }
+            MsgSetDenomMetadata::TYPE_URL => {
+                let msg = MsgSetDenomMetadata::decode(value.as_slice())?;
+                let metadata = msg.metadata.ok_or(anyhow!("metadata not found"))?;
+
+                assert_admin(storage, &sender, &metadata.base)?;
+
+                // Same wire format as the bank `Metadata`, the Injective `decimals` field is dropped
+                let metadata = Metadata::decode(metadata.encode_to_vec().as_slice())?;
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory
+                        .metadata
+                        .insert(metadata.base.clone(), metadata)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+            _ => bail!("type_url not handled on InjectiveStargateMock: {type_url}"),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        path: String,
+        _data: Binary,
+    ) -> AnyResult<Binary> {
+        match path.as_str() {
+            QUERY_PARAMS_PATH => {
+                let denom_creation_fee = CModuleWrapper::load(storage)
+                    .unwrap_or_default()
+                    .token_factory
+                    .fee_creation
+                    .map(|fee_creation| fee_creation.fee)
+                    .unwrap_or_default();
+
+                Ok(to_json_binary(&QueryParamsResponse {
+                    params: Params { denom_creation_fee },
+                })?)
+            }
+            _ => bail!("query path not handled on InjectiveStargateMock: {path}"),
+        }
+    }
+}
+
+fn amount(coin: Option<InjectiveCoin>) -> AnyResult<InjectiveCoin> {
+    coin.ok_or(anyhow!("amount not found"))
+}
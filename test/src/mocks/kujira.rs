@@ -0,0 +1,160 @@
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, Binary, BlockInfo, CustomQuery, Empty, Querier, Storage,
+};
+use cw20_factory_kujira::bindings::{
+    DenomMsg, DenomParams, DenomQuery, FullDenomResponse, KujiraMsg, KujiraQuery,
+    QueryDenomParamsResponse, QUERY_DENOM_PARAMS_PATH,
+};
+use rhaki_cw_plus::{
+    multi_test::{
+        custom_app::{CModuleWrapper, ModuleDb},
+        helper::{
+            anyhow::{bail, Result as AnyResult},
+            cw_multi_test::{AppResponse, CosmosRouter, Module, Stargate},
+        },
+    },
+    storage::interfaces::ItemInterface,
+};
+
+use crate::mocks::osmosis::assert_admin;
+
+/// Stand-in for the `Kujira` `denom` module, driven by `CosmosMsg::Custom(KujiraMsg::Denom(..))`.
+///
+/// Shares the `CTokenFactory` state of `CModuleWrapper`, so the creation fee is the `fee_creation` setted there
+#[derive(Default)]
+pub struct KujiraModule {}
+
+impl Module for KujiraModule {
+    type ExecT = KujiraMsg;
+    type QueryT = KujiraQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: KujiraMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let KujiraMsg::Denom(msg) = msg;
+
+        match msg {
+            DenomMsg::Create { subdenom } => CModuleWrapper::use_db(storage, |db, storage| {
+                db.token_factory
+                    .run_create_denom(api, storage, router, block, sender, subdenom)
+                    .map(|res| res.response)
+            })?,
+            DenomMsg::Mint {
+                denom,
+                amount,
+                recipient,
+            } => CModuleWrapper::use_db(storage, |db, storage| {
+                db.token_factory.run_msg_mint(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender,
+                    denom,
+                    amount,
+                    recipient.to_string(),
+                )
+            })?,
+            DenomMsg::Burn { denom, amount } => CModuleWrapper::use_db(storage, |db, storage| {
+                db.token_factory.run_burn_denom(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender.clone(),
+                    denom,
+                    amount,
+                    sender.to_string(),
+                )
+            })?,
+            DenomMsg::ChangeAdmin { denom, address } => {
+                assert_admin(storage, &sender, &denom)?;
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory.admin.insert(denom, address)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: KujiraQuery,
+    ) -> AnyResult<Binary> {
+        let KujiraQuery::Denom(request) = request;
+
+        match request {
+            DenomQuery::FullDenom {
+                creator_addr,
+                subdenom,
+            } => Ok(to_json_binary(&FullDenomResponse {
+                denom: format!("factory/{creator_addr}/{subdenom}"),
+            })?),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo not handled on KujiraModule")
+    }
+}
+
+/// Serves the `denom` module params, the only stargate query used on `Kujira`
+#[derive(Default)]
+pub struct KujiraStargateMock {}
+
+impl Stargate for KujiraStargateMock {
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        path: String,
+        _data: Binary,
+    ) -> AnyResult<Binary> {
+        match path.as_str() {
+            QUERY_DENOM_PARAMS_PATH => {
+                let creation_fee = CModuleWrapper::load(storage)
+                    .unwrap_or_default()
+                    .token_factory
+                    .fee_creation
+                    .map(|fee_creation| fee_creation.fee)
+                    .unwrap_or_default();
+
+                Ok(to_json_binary(&QueryDenomParamsResponse {
+                    params: DenomParams { creation_fee },
+                })?)
+            }
+            _ => bail!("query path not handled on KujiraStargateMock: {path}"),
+        }
+    }
+}
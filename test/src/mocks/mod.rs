@@ -0,0 +1,8 @@
+pub mod bank;
+pub mod coreum;
+pub mod injective;
+pub mod kujira;
+pub mod neutron;
+pub mod osmosis;
+pub mod receiver;
+pub mod sei;
@@ -0,0 +1,210 @@
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Empty, Querier,
+    Storage,
+};
+use cw20_factory_neutron::bindings::{FullDenomResponse, NeutronMsg, NeutronQuery};
+use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use rhaki_cw_plus::multi_test::{
+    custom_app::{CModuleWrapper, ModuleDb},
+    helper::{
+        anyhow::{bail, Result as AnyResult},
+        cw_multi_test::{AppResponse, CosmosRouter, Module},
+    },
+};
+
+use crate::mocks::{bank::BEFORE_SEND_HOOKS, osmosis::assert_admin};
+
+/// Stand-in for the `Neutron` `tokenfactory`, driven by `CosmosMsg::Custom(NeutronMsg)`.
+///
+/// Shares the `CTokenFactory` state of `CModuleWrapper`.
+/// The params query keeps the `Osmosis` path, so `OsmosisStargateMock` serves it
+#[derive(Default)]
+pub struct NeutronModule {}
+
+impl Module for NeutronModule {
+    type ExecT = NeutronMsg;
+    type QueryT = NeutronQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: NeutronMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            NeutronMsg::CreateDenom { subdenom } => {
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory
+                        .run_create_denom(api, storage, router, block, sender, subdenom)
+                        .map(|res| res.response)
+                })?
+            }
+            NeutronMsg::ChangeAdmin {
+                denom,
+                new_admin_address,
+            } => {
+                assert_admin(storage, &sender, &denom)?;
+
+                let new_admin = api.addr_validate(&new_admin_address)?;
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory.admin.insert(denom, new_admin)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+            NeutronMsg::MintTokens {
+                denom,
+                amount,
+                mint_to_address,
+            } => CModuleWrapper::use_db(storage, |db, storage| {
+                db.token_factory.run_msg_mint(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender,
+                    denom,
+                    amount,
+                    mint_to_address,
+                )
+            })?,
+            NeutronMsg::BurnTokens {
+                denom,
+                amount,
+                burn_from_address,
+            } => {
+                let burn_from_address = if burn_from_address.is_empty() {
+                    sender.to_string()
+                } else {
+                    burn_from_address
+                };
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_burn_denom(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender,
+                        denom,
+                        amount,
+                        burn_from_address,
+                    )
+                })?
+            }
+            NeutronMsg::SetBeforeSendHook {
+                denom,
+                contract_addr,
+            } => {
+                assert_admin(storage, &sender, &denom)?;
+
+                if contract_addr.is_empty() {
+                    BEFORE_SEND_HOOKS.remove(storage, &denom);
+                } else {
+                    BEFORE_SEND_HOOKS.save(storage, &denom, &api.addr_validate(&contract_addr)?)?;
+                }
+
+                Ok(AppResponse::default())
+            }
+            NeutronMsg::ForceTransfer {
+                denom,
+                amount,
+                transfer_from_address,
+                transfer_to_address,
+            } => {
+                assert_admin(storage, &sender, &denom)?;
+
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    api.addr_validate(&transfer_from_address)?,
+                    BankMsg::Send {
+                        to_address: transfer_to_address,
+                        amount: vec![Coin::new(amount.u128(), denom)],
+                    }
+                    .into(),
+                )
+            }
+            NeutronMsg::SetDenomMetadata {
+                description,
+                denom_units,
+                base,
+                display,
+                name,
+                symbol,
+                uri,
+                uri_hash,
+            } => {
+                assert_admin(storage, &sender, &base)?;
+
+                let metadata = Metadata {
+                    description,
+                    denom_units: denom_units
+                        .into_iter()
+                        .map(|unit| DenomUnit {
+                            denom: unit.denom,
+                            exponent: unit.exponent,
+                            aliases: unit.aliases,
+                        })
+                        .collect(),
+                    base: base.clone(),
+                    display,
+                    name,
+                    symbol,
+                    uri,
+                    uri_hash,
+                };
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory.metadata.insert(base, metadata)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: NeutronQuery,
+    ) -> AnyResult<Binary> {
+        match request {
+            NeutronQuery::FullDenom {
+                creator_addr,
+                subdenom,
+            } => Ok(to_json_binary(&FullDenomResponse {
+                denom: format!("factory/{creator_addr}/{subdenom}"),
+            })?),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        _msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("sudo not handled on NeutronModule")
+    }
+}
@@ -0,0 +1,146 @@
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{
+    to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, Coin, CustomQuery, Querier, Storage,
+};
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+    MsgChangeAdmin, MsgForceTransfer, MsgSetBeforeSendHook, Params, QueryParamsResponse,
+};
+use prost::Message;
+use rhaki_cw_plus::{
+    multi_test::{
+        custom_app::CModuleWrapper,
+        custom_chains::osmosis::OsmosisStargateModule,
+        helper::{
+            anyhow::{bail, Result as AnyResult},
+            cw_multi_test::{AppResponse, CosmosRouter, Stargate},
+        },
+    },
+    storage::interfaces::ItemInterface,
+};
+
+use crate::mocks::bank::BEFORE_SEND_HOOKS;
+
+const QUERY_PARAMS_PATH: &str = "/osmosis.tokenfactory.v1beta1.Query/Params";
+
+/// Wrap `OsmosisStargateModule`, adding the handlers it doesn't provide:
+/// - `Query/Params`: returns the `fee_creation` setted on `CModuleWrapper`;
+/// - `MsgChangeAdmin` with an empty `new_admin`: rejected like `ValidateBasic` does;
+/// - `MsgSetBeforeSendHook`: registers the hook called by `HookedBankKeeper`;
+/// - `MsgForceTransfer`: sends the coin from `transfer_from_address` through the bank module.
+#[derive(Default)]
+pub struct OsmosisStargateMock {
+    inner: OsmosisStargateModule,
+}
+
+impl Stargate for OsmosisStargateMock {
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match type_url.as_str() {
+            MsgChangeAdmin::TYPE_URL => {
+                let msg = MsgChangeAdmin::decode(value.as_slice())?;
+
+                if msg.new_admin.is_empty() {
+                    bail!("invalid new admin address: empty address string is not allowed");
+                }
+            }
+            MsgSetBeforeSendHook::TYPE_URL => {
+                let msg = MsgSetBeforeSendHook::decode(value.as_slice())?;
+
+                assert_admin(storage, &sender, &msg.denom)?;
+
+                if msg.cosmwasm_address.is_empty() {
+                    BEFORE_SEND_HOOKS.remove(storage, &msg.denom);
+                } else {
+                    BEFORE_SEND_HOOKS.save(
+                        storage,
+                        &msg.denom,
+                        &api.addr_validate(&msg.cosmwasm_address)?,
+                    )?;
+                }
+
+                return Ok(AppResponse::default());
+            }
+            MsgForceTransfer::TYPE_URL => {
+                let msg = MsgForceTransfer::decode(value.as_slice())?;
+                let amount = msg.amount.unwrap_or_default();
+
+                assert_admin(storage, &sender, &amount.denom)?;
+
+                return router.execute(
+                    api,
+                    storage,
+                    block,
+                    api.addr_validate(&msg.transfer_from_address)?,
+                    BankMsg::Send {
+                        to_address: msg.transfer_to_address,
+                        amount: vec![Coin::new(amount.amount.parse()?, amount.denom)],
+                    }
+                    .into(),
+                );
+            }
+            _ => {}
+        }
+
+        self.inner
+            .execute(api, storage, router, block, sender, type_url, value)
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        path: String,
+        _data: Binary,
+    ) -> AnyResult<Binary> {
+        match path.as_str() {
+            QUERY_PARAMS_PATH => {
+                let denom_creation_fee = CModuleWrapper::load(storage)
+                    .unwrap_or_default()
+                    .token_factory
+                    .fee_creation
+                    .map(|fee_creation| fee_creation.fee)
+                    .unwrap_or_default();
+
+                Ok(to_json_binary(&QueryParamsResponse {
+                    params: Some(Params {
+                        denom_creation_fee: denom_creation_fee
+                            .into_iter()
+                            .map(Into::into)
+                            .collect(),
+                        denom_creation_gas_consume: 0,
+                    }),
+                })?)
+            }
+            _ => bail!("query path not handled on OsmosisStargateMock: {path}"),
+        }
+    }
+}
+
+pub fn assert_admin(storage: &dyn Storage, sender: &Addr, denom: &str) -> AnyResult<()> {
+    let admin = CModuleWrapper::load(storage)
+        .unwrap_or_default()
+        .token_factory
+        .admin
+        .get(denom)
+        .cloned();
+
+    if admin.as_ref() != Some(sender) {
+        bail!("{sender} is not the admin of {denom}")
+    }
+
+    Ok(())
+}
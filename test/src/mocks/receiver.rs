@@ -0,0 +1,66 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_storage_plus::Item;
+
+/// Minimal contract recording what it receives, used to test hooks.
+#[cw_serde]
+pub enum ReceiverExecuteMsg {
+    Receive(Cw20ReceiveMsg),
+    Deposit { memo: String },
+}
+
+#[cw_serde]
+pub enum ReceiverQueryMsg {
+    LastReceive {},
+    LastDeposit {},
+}
+
+#[cw_serde]
+pub struct Deposit {
+    pub sender: Addr,
+    pub funds: Vec<Coin>,
+    pub memo: String,
+}
+
+const LAST_RECEIVE: Item<Cw20ReceiveMsg> = Item::new("last_receive");
+const LAST_DEPOSIT: Item<Deposit> = Item::new("last_deposit");
+
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ReceiverExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ReceiverExecuteMsg::Receive(msg) => LAST_RECEIVE.save(deps.storage, &msg)?,
+        ReceiverExecuteMsg::Deposit { memo } => LAST_DEPOSIT.save(
+            deps.storage,
+            &Deposit {
+                sender: info.sender,
+                funds: info.funds,
+                memo,
+            },
+        )?,
+    }
+
+    Ok(Response::new())
+}
+
+pub fn query(deps: Deps, _env: Env, msg: ReceiverQueryMsg) -> StdResult<Binary> {
+    match msg {
+        ReceiverQueryMsg::LastReceive {} => to_json_binary(&LAST_RECEIVE.may_load(deps.storage)?),
+        ReceiverQueryMsg::LastDeposit {} => to_json_binary(&LAST_DEPOSIT.may_load(deps.storage)?),
+    }
+}
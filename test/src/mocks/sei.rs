@@ -0,0 +1,124 @@
+use std::str::FromStr;
+
+use cosmwasm_schema::{schemars::JsonSchema, serde::de::DeserializeOwned};
+use cosmwasm_std::{Addr, Api, Binary, BlockInfo, CustomQuery, Storage, Uint128};
+use cw20_factory_sei::proto::{
+    Coin as SeiCoin, MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata, TypeUrl,
+};
+use osmosis_std::types::cosmos::bank::v1beta1::Metadata;
+use prost::Message;
+use rhaki_cw_plus::multi_test::{
+    custom_app::{CModuleWrapper, ModuleDb},
+    helper::{
+        anyhow::{anyhow, bail, Result as AnyResult},
+        cw_multi_test::{AppResponse, CosmosRouter, Stargate},
+    },
+};
+
+use crate::mocks::osmosis::assert_admin;
+
+/// Stand-in for the `Sei` `tokenfactory`, sharing the `CTokenFactory` state of `CModuleWrapper`:
+/// - `MsgMint` mints to the sender;
+/// - `MsgBurn` burns from the sender;
+/// - `MsgChangeAdmin` requires a valid `new_admin`, like `ValidateBasic` does.
+///
+/// `Sei` has no denom creation fee, so `fee_creation` on `CModuleWrapper` is expected to be unset
+#[derive(Default)]
+pub struct SeiStargateMock {}
+
+impl Stargate for SeiStargateMock {
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match type_url.as_str() {
+            MsgCreateDenom::TYPE_URL => {
+                let msg = MsgCreateDenom::decode(value.as_slice())?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory
+                        .run_create_denom(api, storage, router, block, sender, msg.subdenom)
+                        .map(|res| res.response)
+                })?
+            }
+            MsgMint::TYPE_URL => {
+                let msg = MsgMint::decode(value.as_slice())?;
+                let coin = amount(msg.amount)?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_msg_mint(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender.clone(),
+                        coin.denom,
+                        Uint128::from_str(&coin.amount)?,
+                        sender.to_string(),
+                    )
+                })?
+            }
+            MsgBurn::TYPE_URL => {
+                let msg = MsgBurn::decode(value.as_slice())?;
+                let coin = amount(msg.amount)?;
+
+                CModuleWrapper::use_db(storage, |db, storage| {
+                    db.token_factory.run_burn_denom(
+                        api,
+                        storage,
+                        router,
+                        block,
+                        sender.clone(),
+                        coin.denom,
+                        Uint128::from_str(&coin.amount)?,
+                        sender.to_string(),
+                    )
+                })?
+            }
+            MsgChangeAdmin::TYPE_URL => {
+                let msg = MsgChangeAdmin::decode(value.as_slice())?;
+
+                assert_admin(storage, &sender, &msg.denom)?;
+
+                let new_admin = api.addr_validate(&msg.new_admin)?;
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory.admin.insert(msg.denom, new_admin)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+            MsgSetDenomMetadata::TYPE_URL => {
+                let msg = MsgSetDenomMetadata::decode(value.as_slice())?;
+                let metadata = msg.metadata.ok_or(anyhow!("metadata not found"))?;
+
+                assert_admin(storage, &sender, &metadata.base)?;
+
+                let metadata = Metadata::decode(metadata.encode_to_vec().as_slice())?;
+
+                CModuleWrapper::use_db(storage, |db, _| {
+                    db.token_factory
+                        .metadata
+                        .insert(metadata.base.clone(), metadata)
+                })?;
+
+                Ok(AppResponse::default())
+            }
+            _ => bail!("type_url not handled on SeiStargateMock: {type_url}"),
+        }
+    }
+}
+
+fn amount(coin: Option<SeiCoin>) -> AnyResult<SeiCoin> {
+    coin.ok_or(anyhow!("amount not found"))
+}